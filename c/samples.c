@@ -0,0 +1,60 @@
+/*
+ * Solves a part of a day with libaoc_2022_rust, for the tests of src/ffi.rs:
+ *
+ *     samples <day> <part> <input file> [<params>]
+ *
+ * prints the answer, or the error and its status on stderr.
+ */
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "aoc.h"
+
+static unsigned char *read_file(const char *path, size_t *len) {
+    FILE *file = fopen(path, "rb");
+    if (file == NULL) {
+        return NULL;
+    }
+    size_t capacity = 4096;
+    unsigned char *data = malloc(capacity);
+    *len = 0;
+    size_t read;
+    while (data != NULL && (read = fread(data + *len, 1, capacity - *len, file)) > 0) {
+        *len += read;
+        if (*len == capacity) {
+            capacity *= 2;
+            unsigned char *grown = realloc(data, capacity);
+            if (grown == NULL) {
+                free(data);
+            }
+            data = grown;
+        }
+    }
+    fclose(file);
+    return data;
+}
+
+int main(int argc, char **argv) {
+    if (argc < 4 || argc > 5) {
+        fprintf(stderr, "usage: %s <day> <part> <input file> [<params>]\n", argv[0]);
+        return 2;
+    }
+    size_t len;
+    unsigned char *input = read_file(argv[3], &len);
+    if (input == NULL) {
+        perror(argv[3]);
+        return 2;
+    }
+
+    char *output = NULL;
+    AocStatus status = aoc_solve((uint8_t)atoi(argv[1]), (uint8_t)atoi(argv[2]), input, len,
+                                 argc == 5 ? argv[4] : NULL, &output);
+    if (status == AOC_STATUS_OK) {
+        printf("%s\n", output);
+    } else {
+        fprintf(stderr, "status %d: %s\n", (int)status, output);
+    }
+    aoc_free_string(output);
+    free(input);
+    return status == AOC_STATUS_OK ? 0 : 1;
+}
@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_2022_rust::day13::Day13;
+use aoc_2022_rust::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = Day13.parse(data);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_2022_rust::day14::Day14;
+use aoc_2022_rust::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = Day14.parse(data);
+});
@@ -0,0 +1,87 @@
+/* The solutions of Advent of Code 2022, from libaoc_2022_rust. */
+
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated from src/ffi.rs by `cargo test`: don't edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ What [`aoc_solve`] tells about the string it gives.
+ */
+typedef enum AocStatus {
+  /*
+   the string is the answer
+   */
+  AOC_STATUS_OK = 0,
+  /*
+   no solution for the day
+   */
+  AOC_STATUS_UNKNOWN_DAY = 1,
+  /*
+   the part is neither 1 nor 2
+   */
+  AOC_STATUS_UNKNOWN_PART = 2,
+  /*
+   the input is not UTF-8, or the day can't parse it
+   */
+  AOC_STATUS_INVALID_INPUT = 3,
+  /*
+   the parameters are malformed, or unknown to the day
+   */
+  AOC_STATUS_INVALID_PARAMS = 4,
+  /*
+   a pointer is null where it can't be
+   */
+  AOC_STATUS_NULL_POINTER = 5,
+  /*
+   the solution panicked on the input
+   */
+  AOC_STATUS_PANICKED = 6,
+} AocStatus;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ Solves `part` (1 or 2) of `day` on the `len` bytes of `input`.
+
+ `params` is null, or gives the puzzle parameters that differ from the real puzzle, like
+ `row=10&search_limit=20` for the sample of day 15.
+
+ `*output` receives the answer when the status is `Ok`, or else the error, as a string that
+ belongs to the caller and is freed with [`aoc_free_string`]. Only a null `output` is left
+ as is. Answers drawn with letters, like the one of day 10, span several lines.
+
+ # Safety
+
+ `input` points to `len` bytes (it may be null when `len` is 0), `params` is null or a
+ nul-terminated string, and `output` is null or points to a writable pointer.
+ */
+enum AocStatus aoc_solve(uint8_t day,
+                         uint8_t part,
+                         const uint8_t *input,
+                         size_t len,
+                         const char *params,
+                         char **output);
+
+/*
+ Frees a string given by [`aoc_solve`]. Null is ignored.
+
+ # Safety
+
+ `string` is null, or a string given by [`aoc_solve`] and not freed yet.
+ */
+void aoc_free_string(char *string);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC_H */
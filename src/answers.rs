@@ -0,0 +1,262 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::runner::Part;
+
+/// Known-good answers, checked in at the root of the repository.
+pub const DEFAULT_PATH: &str = "answers.json";
+
+/// Hash of an input file, so that answers are only compared against the input they were
+/// computed from.
+pub fn input_hash(data: &str) -> String {
+    Sha256::digest(data.as_bytes())
+        .iter()
+        .take(8)
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u8,
+    #[serde(with = "part_number")]
+    pub part: Part,
+    pub input: String,
+    /// multi-line answers (like the letters drawn by day 10) are stored line by line
+    #[serde(with = "multiline")]
+    pub answer: String,
+}
+
+#[derive(Debug, Default)]
+pub struct AnswerDb {
+    entries: Vec<Entry>,
+}
+
+/// A JSON file kept by the commands (answers, benchmarks, history, cache) couldn't be read or
+/// written.
+#[derive(Debug)]
+pub enum JsonFileError {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Format {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+impl Display for JsonFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonFileError::Io { path, source } => {
+                write!(f, "can't access {}: {source}", path.display())
+            }
+            JsonFileError::Format { path, source } => {
+                write!(
+                    f,
+                    "{} is not in the expected format: {source}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for JsonFileError {}
+
+/// Reads the JSON file at `path`, shared by the files that the commands keep; a missing file
+/// is the default value, like an empty list.
+pub(crate) fn load_json<T: DeserializeOwned + Default>(path: &Path) -> Result<T, JsonFileError> {
+    let json = match std::fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(T::default()),
+        Err(source) => {
+            return Err(JsonFileError::Io {
+                path: path.to_path_buf(),
+                source,
+            })
+        }
+    };
+    serde_json::from_str(&json).map_err(|source| JsonFileError::Format {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Writes `value` to the JSON file at `path`, indented to be read in diffs, creating its
+/// directory when needed.
+pub(crate) fn save_json<T: Serialize + ?Sized>(
+    path: &Path,
+    value: &T,
+) -> Result<(), JsonFileError> {
+    let io_error = |source| JsonFileError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let mut json = serde_json::to_string_pretty(value).unwrap();
+    json.push('\n');
+    std::fs::write(path, json).map_err(io_error)
+}
+
+impl AnswerDb {
+    /// Loads the answers from `path`; a missing file is an empty database.
+    pub fn load(path: &Path) -> Result<Self, JsonFileError> {
+        Ok(AnswerDb {
+            entries: load_json(path)?,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), JsonFileError> {
+        save_json(path, &self.entries)
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|e| e.day == day && e.part == part && e.input == input)
+            .map(|e| e.answer.as_str())
+    }
+
+    /// Records a new known-good answer, keeping the entries sorted.
+    pub fn insert(&mut self, entry: Entry) {
+        let key = |e: &Entry| (e.day, e.part, e.input.clone());
+        match self.entries.binary_search_by_key(&key(&entry), key) {
+            Ok(idx) => self.entries[idx] = entry,
+            Err(idx) => self.entries.insert(idx, entry),
+        }
+    }
+}
+
+pub(crate) mod part_number {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::runner::Part;
+
+    pub fn serialize<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(match part {
+            Part::One => 1,
+            Part::Two => 2,
+        })
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Part, D::Error> {
+        match u8::deserialize(deserializer)? {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            n => Err(serde::de::Error::custom(format!("no part {n} in a puzzle"))),
+        }
+    }
+}
+
+pub(crate) mod multiline {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum Stored {
+        Line(String),
+        Lines(Vec<String>),
+    }
+
+    pub fn serialize<S: Serializer>(answer: &str, serializer: S) -> Result<S::Ok, S::Error> {
+        if answer.contains('\n') {
+            Stored::Lines(answer.lines().map(String::from).collect()).serialize(serializer)
+        } else {
+            Stored::Line(String::from(answer)).serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        Ok(match Stored::deserialize(deserializer)? {
+            Stored::Line(line) => line,
+            Stored::Lines(lines) => lines.join("\n"),
+        })
+    }
+
+    /// For optional answers, with `#[serde(default)]` for the missing ones.
+    #[cfg(test)]
+    pub fn optional<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<String>, D::Error> {
+        deserialize(deserializer).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{input_hash, AnswerDb, Entry, JsonFileError};
+    use crate::runner::Part;
+    use crate::stand_in::temp_dir;
+
+    fn entry(day: u8, part: Part, answer: &str) -> Entry {
+        Entry {
+            day,
+            part,
+            input: input_hash("input"),
+            answer: String::from(answer),
+        }
+    }
+
+    #[test]
+    fn hash_depends_on_the_content_only() {
+        assert_eq!(input_hash("1\n2\n"), input_hash(&String::from("1\n2\n")));
+        assert_ne!(input_hash("1\n2\n"), input_hash("1\n3\n"));
+        assert_eq!(input_hash("").len(), 16);
+    }
+
+    #[test]
+    fn multiline_answers_are_stored_line_by_line() {
+        let art = entry(10, Part::Two, "#  #\n####\n#  #");
+        let json = serde_json::to_value(&art).unwrap();
+        assert_eq!(json["part"], 2);
+        assert_eq!(json["answer"], serde_json::json!(["#  #", "####", "#  #"]));
+        assert_eq!(serde_json::from_value::<Entry>(json).unwrap(), art);
+
+        let number = entry(1, Part::One, "69281");
+        let json = serde_json::to_value(&number).unwrap();
+        assert_eq!(json["answer"], "69281");
+        assert_eq!(serde_json::from_value::<Entry>(json).unwrap(), number);
+    }
+
+    #[test]
+    fn answers_are_found_by_day_part_and_input() {
+        let mut db = AnswerDb::default();
+        db.insert(entry(2, Part::One, "15"));
+        db.insert(entry(1, Part::Two, "45000"));
+        db.insert(entry(1, Part::One, "24000"));
+
+        let input = input_hash("input");
+        assert_eq!(db.get(1, Part::One, &input), Some("24000"));
+        assert_eq!(db.get(1, Part::Two, &input), Some("45000"));
+        assert_eq!(db.get(2, Part::Two, &input), None);
+        assert_eq!(db.get(1, Part::One, &input_hash("other input")), None);
+        let days: Vec<(u8, Part)> = db.entries.iter().map(|e| (e.day, e.part)).collect();
+        assert_eq!(days, vec![(1, Part::One), (1, Part::Two), (2, Part::One)]);
+    }
+
+    #[test]
+    fn saved_in_a_new_directory_and_loaded_back() {
+        let dir = temp_dir("answers");
+        let path = dir.join("new").join("answers.json");
+        assert!(AnswerDb::load(&path).unwrap().entries.is_empty());
+
+        let mut db = AnswerDb::default();
+        db.insert(entry(10, Part::Two, "#  #\n####"));
+        db.save(&path).unwrap();
+        assert_eq!(AnswerDb::load(&path).unwrap().entries, db.entries);
+
+        std::fs::write(&path, "{").unwrap();
+        let error = AnswerDb::load(&path).unwrap_err();
+        assert!(matches!(error, JsonFileError::Format { .. }));
+        assert!(error.to_string().starts_with(&path.display().to_string()));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
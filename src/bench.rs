@@ -0,0 +1,216 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answers::{load_json, save_json, JsonFileError};
+use crate::parse::ParseError;
+use crate::runner::{Part, Solver};
+
+/// Where the timings of the last benchmark are kept, to compare the next one with.
+pub const DEFAULT_PATH: &str = "target/bench.json";
+
+/// Changes smaller than this are noise, whatever their ratio: a few microseconds are
+/// enough to double the time of the fastest days.
+pub const NOISE: Duration = Duration::from_micros(20);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Step {
+    pub const ALL: [Step; 3] = [Step::Parse, Step::Part1, Step::Part2];
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part1 => write!(f, "part 1"),
+            Step::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Median times of the steps of a day, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub day: u8,
+    pub runs: usize,
+    pub parse_ns: u64,
+    pub part1_ns: u64,
+    pub part2_ns: u64,
+}
+
+impl DayTimings {
+    pub fn time(&self, step: Step) -> Duration {
+        Duration::from_nanos(match step {
+            Step::Parse => self.parse_ns,
+            Step::Part1 => self.part1_ns,
+            Step::Part2 => self.part2_ns,
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BenchReport {
+    pub days: Vec<DayTimings>,
+}
+
+impl BenchReport {
+    /// Loads the timings saved at `path`; a missing file is an empty report.
+    pub fn load(path: &Path) -> Result<Self, JsonFileError> {
+        Ok(BenchReport {
+            days: load_json(path)?,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), JsonFileError> {
+        save_json(path, &self.days)
+    }
+
+    pub fn get(&self, day: u8) -> Option<&DayTimings> {
+        self.days.iter().find(|t| t.day == day)
+    }
+
+    /// Replaces the timings of a day, keeping the days sorted.
+    pub fn update(&mut self, timings: DayTimings) {
+        match self.days.binary_search_by_key(&timings.day, |t| t.day) {
+            Ok(idx) => self.days[idx] = timings,
+            Err(idx) => self.days.insert(idx, timings),
+        }
+    }
+}
+
+/// Solves `data` `runs` times, keeping the median time of each step.
+pub fn measure(
+    day: u8,
+    solver: &dyn Solver,
+    data: &str,
+    runs: usize,
+) -> Result<DayTimings, ParseError> {
+    let mut times: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..runs.max(1) {
+        let solved = solver.solve(data, &Part::BOTH)?;
+        times[0].push(solved.parse_time);
+        times[1].push(solved.answers[0].elapsed);
+        times[2].push(solved.answers[1].elapsed);
+    }
+    let [parse, part1, part2] = times.map(|times| median(times).as_nanos() as u64);
+    Ok(DayTimings {
+        day,
+        runs: runs.max(1),
+        parse_ns: parse,
+        part1_ns: part1,
+        part2_ns: part2,
+    })
+}
+
+fn median(mut times: Vec<Duration>) -> Duration {
+    times.sort();
+    times[times.len() / 2]
+}
+
+/// Time of a step in two benchmarks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub day: u8,
+    pub step: Step,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Change {
+    /// Relative change, in percent.
+    pub fn percent(&self) -> f64 {
+        (self.after.as_secs_f64() / self.before.as_secs_f64().max(1e-9) - 1.0) * 100.0
+    }
+
+    /// Slower by more than `threshold` percent, and by more than the [`NOISE`].
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.after > self.before + NOISE && self.percent() > threshold
+    }
+}
+
+/// Changes of every step of the days found in both benchmarks.
+pub fn compare(before: &BenchReport, after: &BenchReport) -> Vec<Change> {
+    after
+        .days
+        .iter()
+        .filter_map(|new| before.get(new.day).map(|old| (old, new)))
+        .flat_map(|(old, new)| {
+            Step::ALL.map(|step| Change {
+                day: new.day,
+                step,
+                before: old.time(step),
+                after: new.time(step),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{compare, measure, median, BenchReport, Change, DayTimings, Step};
+    use crate::runner::solver;
+    use std::time::Duration;
+
+    fn timings(day: u8, parse_ns: u64, part1_ns: u64, part2_ns: u64) -> DayTimings {
+        DayTimings {
+            day,
+            runs: 5,
+            parse_ns,
+            part1_ns,
+            part2_ns,
+        }
+    }
+
+    #[test]
+    fn median_of_the_runs() {
+        let ms = Duration::from_millis;
+        assert_eq!(median(vec![ms(5), ms(1), ms(100)]), ms(5));
+        assert_eq!(median(vec![ms(7)]), ms(7));
+    }
+
+    #[test]
+    fn measure_every_step() {
+        let data = include_str!("../inputs/day07.sample.txt");
+        let timings = measure(7, solver(7).unwrap().as_ref(), data, 3).unwrap();
+        assert_eq!(timings.day, 7);
+        assert_eq!(timings.runs, 3);
+    }
+
+    #[test]
+    fn regressions_beyond_the_threshold() {
+        let mut before = BenchReport::default();
+        before.update(timings(8, 1_000_000, 1_000_000, 1_000));
+        before.update(timings(1, 1_000_000, 1_000_000, 1_000_000));
+        let mut after = BenchReport::default();
+        after.update(timings(8, 1_050_000, 2_000_000, 3_000));
+        after.update(timings(15, 1_000, 1_000, 1_000));
+
+        let changes = compare(&before, &after);
+        assert_eq!(changes.len(), 3);
+        let regressions: Vec<Step> = changes
+            .iter()
+            .filter(|c| c.is_regression(10.0))
+            .map(|c| c.step)
+            .collect();
+        // parse is within the threshold, and part 2 is 3 times slower but still too fast
+        // to tell
+        assert_eq!(regressions, vec![Step::Part1]);
+
+        let faster = Change {
+            day: 8,
+            step: Step::Parse,
+            before: Duration::from_millis(4),
+            after: Duration::from_millis(3),
+        };
+        assert!((faster.percent() + 25.0).abs() < 1e-9);
+        assert!(!faster.is_regression(10.0));
+    }
+}
@@ -0,0 +1,1137 @@
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use aoc_2022_rust::answers::{self, AnswerDb, Entry};
+use aoc_2022_rust::bench::{self, BenchReport, Change, Step};
+use aoc_2022_rust::cache::{self, Cache};
+use aoc_2022_rust::compare::{self, Checked, ProfileRun};
+use aoc_2022_rust::fetch::{self, FetchError, Fetched, Fetcher};
+use aoc_2022_rust::generate;
+use aoc_2022_rust::input::{self, Source};
+use aoc_2022_rust::memory::{self, CountingAllocator, Memory};
+use aoc_2022_rust::pool;
+use aoc_2022_rust::report::{Failure, Record};
+use aoc_2022_rust::runner::{print_answer, solver, Answer, Part, Solved, DAYS};
+use aoc_2022_rust::scaffold;
+use aoc_2022_rust::server::{self, Server};
+use aoc_2022_rust::simulation::{self, Timeline};
+use aoc_2022_rust::submit::{self, Attempt, History, Submitter, Verdict};
+use aoc_2022_rust::tui::{self, Player};
+use aoc_2022_rust::usage;
+use clap::{Parser, Subcommand, ValueEnum};
+use tracing::{info, warn};
+use tracing_subscriber::EnvFilter;
+
+/// Counts nothing until `--memory` enables it.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// print diagnostics on stderr: -v for info, -vv for debug, -vvv for everything
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// print every diagnostic of a day, like the drawings of day 05 and day 14
+    #[arg(long, global = true, value_name = "DAY")]
+    trace: Vec<u8>,
+    /// use the inputs of another account, in `inputs/<PROFILE>/` instead of `inputs/`
+    #[arg(long, global = true, env = "AOC_PROFILE", value_parser = input::profile_name)]
+    profile: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day
+    Run {
+        /// day of the puzzle (1 to 15)
+        day: u8,
+        /// part to solve
+        #[arg(short, long, value_enum, default_value_t = PartArg::Both)]
+        part: PartArg,
+        /// puzzle input, instead of `inputs/dayNN.txt` (`-` reads stdin)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// solve again even the parts whose answer is in the cache
+        #[arg(long)]
+        no_cache: bool,
+        /// count the allocations, bytes allocated and heap peak of each step, and give the peak
+        /// resident memory (solves again the parts in the cache)
+        #[arg(long)]
+        memory: bool,
+    },
+    /// Solve every day and print a summary table, then the days from the slowest
+    All {
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// solve again even the parts whose answer is in the cache
+        #[arg(long)]
+        no_cache: bool,
+        /// count the allocations, bytes allocated and heap peak of each step, and give the peak
+        /// resident memory (solves again the parts in the cache)
+        #[arg(long)]
+        memory: bool,
+        /// days solved side by side, each with its parts side by side (1 solves everything in
+        /// turn)
+        #[arg(short = 'j', long, default_value_t = pool::default_threads())]
+        threads: usize,
+    },
+    /// Solve every day and compare with the known-good answers
+    Verify {
+        /// file of known-good answers
+        #[arg(long, default_value = answers::DEFAULT_PATH)]
+        answers: PathBuf,
+        /// add the answers missing from the file (mismatches are left as they are)
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time the parsing and both parts of some days, and compare with a previous benchmark
+    Bench {
+        /// days to benchmark (all of them by default)
+        days: Vec<u8>,
+        /// number of runs of each day, the median time is kept
+        #[arg(short, long, default_value_t = 5)]
+        runs: usize,
+        /// file where the timings are saved
+        #[arg(long, default_value = bench::DEFAULT_PATH)]
+        save: PathBuf,
+        /// timings to compare with, instead of the ones previously saved
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// slowdown, in percent, reported as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Download the puzzle inputs missing from `inputs/`
+    Fetch {
+        /// days to download (all of them by default)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
+        /// value of the `session` cookie of the site, once logged in
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: Option<String>,
+        /// site to download from
+        #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Send the answer of a part to the site, unless it is known to be wrong
+    Submit {
+        /// day of the puzzle (1 to 15)
+        day: u8,
+        /// part of the puzzle
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// answer to send, instead of the one computed from `inputs/dayNN.txt`
+        answer: Option<String>,
+        /// value of the `session` cookie of the site, once logged in
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: Option<String>,
+        /// site to send the answer to
+        #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+        /// file of the previous attempts
+        #[arg(long, default_value = submit::HISTORY_PATH)]
+        history: PathBuf,
+    },
+    /// Solve a day on the inputs of every profile, to see the answers and timings side by side
+    Compare {
+        /// day of the puzzle (1 to 15)
+        day: u8,
+        /// part to solve
+        #[arg(short, long, value_enum, default_value_t = PartArg::Both)]
+        part: PartArg,
+        /// profiles to compare (the default inputs and every profile by default)
+        #[arg(value_parser = input::profile_name)]
+        profiles: Vec<String>,
+        /// file of known-good answers, to tell the right answers from the wrong ones
+        #[arg(long, default_value = answers::DEFAULT_PATH)]
+        answers: PathBuf,
+    },
+    /// Print a random input of a day, to stress its solution (`aoc generate 9 | aoc run 9 -i -`)
+    Generate {
+        /// day of the puzzle (1 to 15)
+        day: u8,
+        /// number of items of the input (lines, elves, directories, ... depending on the day)
+        #[arg(short, long, default_value_t = 100)]
+        size: usize,
+        /// seed of the random input, to get the same one again (a random one by default)
+        #[arg(long)]
+        seed: Option<u64>,
+        /// file to write the input to, instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Watch a day solved step by step in the terminal (days 5, 9, 10, 11 and 14)
+    Play {
+        /// day of the puzzle
+        day: u8,
+        /// part to watch
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2), default_value_t = 1)]
+        part: u8,
+        /// puzzle input, instead of `inputs/dayNN.txt` (`-` reads stdin)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// print the state after this step instead of playing, like `aoc play 14 --at 100`
+        #[arg(long, value_name = "STEP")]
+        at: Option<usize>,
+    },
+    /// Answer HTTP requests: `GET /days`, and `POST /days/{n}/parts/{p}` with the input as body
+    Serve {
+        /// address to listen on
+        #[arg(long, default_value = server::DEFAULT_ADDR)]
+        addr: String,
+        /// largest input accepted, in bytes
+        #[arg(long, default_value_t = server::DEFAULT_MAX_INPUT)]
+        max_input: usize,
+        /// seconds to wait for an answer before giving up
+        #[arg(
+            long,
+            value_parser = server::timeout_secs,
+            default_value_t = server::DEFAULT_TIMEOUT.as_secs_f64()
+        )]
+        timeout: f64,
+        /// most solves running at once, the ones given up included
+        #[arg(
+            short = 'j',
+            long,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+            default_value_t = pool::default_threads()
+        )]
+        threads: usize,
+    },
+    /// Manage the answers kept by `run` and `all`, in `target/answers-cache.json`
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+    /// Start a new day: its module, input and sample files, registered everywhere
+    NewDay {
+        /// day of the puzzle, the one after the last solved day
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Forget every answer, for every day to be solved again
+    Clear,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum PartArg {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// for people
+    Text,
+    /// a JSON object per line, for each answer (or failed day)
+    Json,
+}
+
+impl PartArg {
+    fn parts(self) -> &'static [Part] {
+        match self {
+            PartArg::One => &[Part::One],
+            PartArg::Two => &[Part::Two],
+            PartArg::Both => &Part::BOTH,
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    init_logging(cli.verbose, &cli.trace);
+    let source = Source::from_profile(cli.profile.clone());
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            no_cache,
+            memory,
+        } => {
+            if memory {
+                memory::enable();
+            }
+            let Some(solver) = solver(day) else {
+                eprintln!("no solution for day {day}");
+                std::process::exit(2);
+            };
+            let source = match Source::from_arg(input) {
+                Source::Default => source,
+                other => other,
+            };
+            let data = match input::load(day, &source) {
+                Ok(data) => data,
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    std::process::exit(1);
+                }
+            };
+            let cache = load_cache(no_cache || memory);
+            let solved = match &cache {
+                Some(cache) => cache.solve(day, solver.as_ref(), &data, part.parts(), false),
+                None => solver.solve(&data, part.parts()),
+            };
+            let solved = match solved {
+                Ok(solved) => solved,
+                Err(error) => {
+                    eprintln!("error: {}", error.diagnostic(&data));
+                    std::process::exit(1);
+                }
+            };
+            save_cache(cache.as_ref());
+            match format {
+                Format::Text => {
+                    for answer in &solved.answers {
+                        print_answer(day, answer);
+                    }
+                    if memory {
+                        print_memory(&[(day, &solved)]);
+                    }
+                }
+                Format::Json => print_records(day, &data, &solved),
+            }
+        }
+        Command::All {
+            format,
+            no_cache,
+            memory,
+            threads,
+        } => {
+            if memory {
+                memory::enable();
+            }
+            let days: Vec<u8> = DAYS.collect();
+            let cache = load_cache(no_cache || memory);
+            let (start, cpu_start) = (Instant::now(), usage::cpu_time());
+            let results = pool::map(&days, threads, |&day| {
+                solve_day(day, &source, threads > 1, cache.as_ref())
+            });
+            let wall_time = start.elapsed();
+            let cpu_time = cpu_start
+                .zip(usage::cpu_time())
+                .map(|(start, end)| end.saturating_sub(start));
+            save_cache(cache.as_ref());
+            info!(?wall_time, ?cpu_time, threads, "every day solved");
+            match format {
+                Format::Text => {
+                    let results: Vec<(u8, Result<Solved, String>)> = days
+                        .into_iter()
+                        .zip(results)
+                        .map(|(day, solved)| (day, solved.map(|(_, solved)| solved)))
+                        .collect();
+                    print_summary(&results);
+                    print_timings(&results, wall_time, cpu_time, threads);
+                    if memory {
+                        let solved: Vec<(u8, &Solved)> = results
+                            .iter()
+                            .filter_map(|(day, solved)| Some((*day, solved.as_ref().ok()?)))
+                            .collect();
+                        print_memory(&solved);
+                    }
+                }
+                Format::Json => {
+                    for (day, solved) in days.into_iter().zip(results) {
+                        match solved {
+                            Ok((data, solved)) => print_records(day, &data, &solved),
+                            Err(error) => {
+                                let failure = Failure { day, error };
+                                println!("{}", serde_json::to_string(&failure).unwrap());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Command::Verify { answers, record } => {
+            if !verify(&answers, &source, record) {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            runs,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let days = if days.is_empty() {
+                DAYS.collect()
+            } else {
+                days
+            };
+            let baseline = baseline.as_ref().unwrap_or(&save);
+            if !run_bench(&days, &source, runs, &save, baseline, threshold) {
+                std::process::exit(1);
+            }
+        }
+        Command::Fetch {
+            days,
+            session,
+            base_url,
+        } => {
+            let days = if days.is_empty() {
+                DAYS.collect()
+            } else {
+                days
+            };
+            let mut fetcher = Fetcher::new(&base_url, session);
+            if let Some(profile) = &cli.profile {
+                fetcher = fetcher.inputs_dir(&input::profile_dir(profile));
+            }
+            let mut failed = false;
+            for day in days {
+                match fetcher.fetch(day) {
+                    Ok(Fetched::Cached(path)) => println!("day {day:02}: {}", path.display()),
+                    Ok(Fetched::Downloaded(path)) => {
+                        println!("day {day:02}: {} (downloaded)", path.display())
+                    }
+                    Err(error) => {
+                        eprintln!("day {day:02}: error: {error}");
+                        failed = true;
+                    }
+                }
+            }
+            if failed {
+                std::process::exit(1);
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            session,
+            base_url,
+            history,
+        } => {
+            let part = if part == 1 { Part::One } else { Part::Two };
+            match submit(day, &source, part, answer, session, &base_url, &history) {
+                Ok(true) => {}
+                Ok(false) => std::process::exit(1),
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Compare {
+            day,
+            part,
+            profiles,
+            answers,
+        } => {
+            let db = match AnswerDb::load(&answers) {
+                Ok(db) => db,
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    std::process::exit(1);
+                }
+            };
+            let profiles = if profiles.is_empty() {
+                match input::profiles(Path::new(input::DIR)) {
+                    Ok(profiles) => std::iter::once(None).chain(profiles.into_iter().map(Some)),
+                    Err(error) => {
+                        eprintln!("error: {error}");
+                        std::process::exit(1);
+                    }
+                }
+                .collect()
+            } else {
+                profiles.into_iter().map(Some).collect::<Vec<_>>()
+            };
+            // one input after the other, for the timings to be comparable
+            let runs: Vec<ProfileRun> = profiles
+                .into_iter()
+                .map(|profile| {
+                    let name = profile.clone().unwrap_or_else(|| String::from("(default)"));
+                    let source = Source::from_profile(profile);
+                    compare::run(day, part.parts(), &name, &source, &db)
+                })
+                .collect();
+            print_comparison(day, &runs);
+            if runs.iter().any(ProfileRun::is_failure) {
+                std::process::exit(1);
+            }
+        }
+        Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let seed = seed.unwrap_or_else(rand::random);
+            info!(seed, "generating day {day}");
+            let Some(data) = generate::generate(day, size, seed) else {
+                eprintln!("no generator for day {day}");
+                std::process::exit(2);
+            };
+            match output {
+                Some(path) => {
+                    if let Err(error) = std::fs::write(&path, data) {
+                        eprintln!("error: cannot write {}: {error}", path.display());
+                        std::process::exit(1);
+                    }
+                }
+                None => print!("{data}"),
+            }
+        }
+        Command::Play {
+            day,
+            part,
+            input,
+            at,
+        } => {
+            let source = match Source::from_arg(input) {
+                Source::Default => source,
+                other => other,
+            };
+            let part = if part == 1 { Part::One } else { Part::Two };
+            if let Err(error) = play(day, part, &source, at) {
+                eprintln!("error: {error}");
+                std::process::exit(1);
+            }
+        }
+        Command::Serve {
+            addr,
+            max_input,
+            timeout,
+            threads,
+        } => {
+            let server = match Server::bind(&addr) {
+                Ok(server) => server,
+                Err(error) => {
+                    eprintln!("error: cannot listen on {addr}: {error}");
+                    std::process::exit(1);
+                }
+            };
+            println!("listening on http://{}", server.addr());
+            server
+                .max_input(max_input)
+                .timeout(Duration::from_secs_f64(timeout))
+                .max_running(threads)
+                .serve();
+        }
+        Command::Cache {
+            command: CacheCommand::Clear,
+        } => match Cache::clear(Path::new(cache::DEFAULT_PATH)) {
+            Ok(true) => println!("cleared {}", cache::DEFAULT_PATH),
+            Ok(false) => println!("no cache to clear"),
+            Err(error) => {
+                eprintln!("error: {error}");
+                std::process::exit(1);
+            }
+        },
+        Command::NewDay { day } => match scaffold::new_day(Path::new("."), day) {
+            Ok(changes) => {
+                for path in changes.created {
+                    println!("created {}", path.display());
+                }
+                for path in changes.updated {
+                    println!("updated {}", path.display());
+                }
+                println!(
+                    "\nnext: paste the sample into inputs/day{day:02}.sample.txt and its answers \
+                     into the tests of src/day{day:02}.rs and inputs/samples.json, then \
+                     `aoc fetch {day}`"
+                );
+            }
+            Err(error) => {
+                eprintln!("error: {error}");
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
+/// Diagnostics go to stderr, so that stdout only has the answers. `RUST_LOG` is used instead
+/// of the verbosity when it is set.
+fn init_logging(verbose: u8, traced_days: &[u8]) {
+    let level = match verbose {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    };
+    let mut filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+    for day in traced_days {
+        let directive = format!("aoc_2022_rust::day{day:02}=trace");
+        filter = filter.add_directive(directive.parse().unwrap());
+    }
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
+
+/// The answers computed before, unless `no_cache`. A cache that can't be read is only
+/// worth a warning: the days are solved again.
+fn load_cache(no_cache: bool) -> Option<Cache> {
+    if no_cache {
+        return None;
+    }
+    match Cache::load(Path::new(cache::DEFAULT_PATH)) {
+        Ok(cache) => Some(cache),
+        Err(error) => {
+            warn!("{error}, solving every day again");
+            Some(Cache::default())
+        }
+    }
+}
+
+fn save_cache(cache: Option<&Cache>) {
+    if let Some(Err(error)) = cache.map(|cache| cache.save(Path::new(cache::DEFAULT_PATH))) {
+        warn!("{error}");
+    }
+}
+
+/// Solves both parts of `day` on its input from `source`, giving the input too. With `parallel`,
+/// the parts are solved side by side. With a `cache`, only the parts not in it are solved.
+fn solve_day(
+    day: u8,
+    source: &Source,
+    parallel: bool,
+    cache: Option<&Cache>,
+) -> Result<(String, Solved), String> {
+    let solver = solver(day).unwrap();
+    let data = input::load(day, source).map_err(|error| error.to_string())?;
+    let solved = match cache {
+        Some(cache) => cache.solve(day, solver.as_ref(), &data, &Part::BOTH, parallel),
+        None if parallel => solver.solve_parallel(&data, &Part::BOTH),
+        None => solver.solve(&data, &Part::BOTH),
+    };
+    match solved {
+        Ok(solved) => Ok((data, solved)),
+        Err(error) => Err(error.diagnostic(&data)),
+    }
+}
+
+/// Sends the answer of a part, after checking it against the previous attempts, and tells
+/// whether it is right.
+fn submit(
+    day: u8,
+    source: &Source,
+    part: Part,
+    answer: Option<String>,
+    session: Option<String>,
+    base_url: &str,
+    history_path: &Path,
+) -> Result<bool, String> {
+    let Some(solver) = solver(day) else {
+        return Err(format!("no solution for day {day}"));
+    };
+    let data = input::load(day, source).map_err(|error| error.to_string())?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solved = solver
+                .solve(&data, &[part])
+                .map_err(|error| error.diagnostic(&data))?;
+            solved.answers[0].value.clone()
+        }
+    };
+    if answer.contains('\n') {
+        return Err(format!(
+            "the answer is drawn, give the letters it shows:\n{answer}"
+        ));
+    }
+    let input = answers::input_hash(&data);
+    let mut history = History::load(history_path).map_err(|error| error.to_string())?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    if let Err(refusal) = history.check(day, part, &input, &answer, now) {
+        println!("Day {day:02} part {part}: not submitted, {refusal}");
+        return Ok(false);
+    }
+
+    let session = session.ok_or_else(|| FetchError::NoSession.to_string())?;
+    let reply = Submitter::new(base_url, session)
+        .submit(day, part, &answer)
+        .map_err(|error| error.to_string())?;
+    history.record(Attempt {
+        day,
+        part,
+        input,
+        answer: answer.clone(),
+        verdict: reply.verdict,
+        at: now,
+        retry_at: reply.wait.map(|wait| now + wait.as_secs()),
+    });
+    history
+        .save(history_path)
+        .map_err(|error| error.to_string())?;
+    println!("Day {day:02} part {part}: {answer} is {}", reply.verdict);
+    info!("{}", reply.message);
+    Ok(reply.verdict == Verdict::Correct)
+}
+
+/// Plays the simulation of a part of `day` in the terminal, or prints its state after step
+/// `at`.
+fn play(day: u8, part: Part, source: &Source, at: Option<usize>) -> Result<(), String> {
+    let data = input::load(day, source).map_err(|error| error.to_string())?;
+    let Some(simulation) = simulation::simulation(day, part, &data) else {
+        let days: Vec<String> = simulation::DAYS.iter().map(u8::to_string).collect();
+        return Err(format!(
+            "no simulation for day {day}, only for days {}",
+            days.join(", ")
+        ));
+    };
+    let simulation = simulation.map_err(|error| error.diagnostic(&data))?;
+    let mut timeline = Timeline::new(simulation);
+    match at {
+        Some(step) => {
+            timeline.go_to(step);
+            println!(
+                "Day {day:02} part {part}, step {}: {}",
+                timeline.step(),
+                timeline.current().status()
+            );
+            println!("{}", timeline.current().render().trim_end());
+            Ok(())
+        }
+        None => {
+            let mut player = Player::new(&format!("Day {day:02} part {part}"), timeline);
+            tui::run(&mut player).map_err(|error| error.to_string())
+        }
+    }
+}
+
+/// Compares the answers of every day with the ones in the `path` file, and tells whether
+/// they all match.
+fn verify(path: &Path, source: &Source, record: bool) -> bool {
+    let mut db = match AnswerDb::load(path) {
+        Ok(db) => db,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return false;
+        }
+    };
+    let (mut nb_ok, mut mismatches, mut missing, mut failures) = (0, 0, 0, 0);
+    let mut total = Duration::ZERO;
+    for day in DAYS {
+        let (data, solved) = match solve_day(day, source, false, None) {
+            Ok(solved) => solved,
+            Err(error) => {
+                println!("Day {day:02}          FAILED");
+                eprintln!("error: {error}");
+                failures += 1;
+                continue;
+            }
+        };
+        let input = answers::input_hash(&data);
+        total += solved.total_time();
+        for answer in solved.answers {
+            let time = format_duration(answer.elapsed);
+            let label = format!("Day {day:02} part {}", answer.part);
+            match db.get(day, answer.part, &input) {
+                Some(expected) if expected == answer.value => {
+                    println!("{label}  ok        {time:>12}");
+                    nb_ok += 1;
+                }
+                Some(expected) => {
+                    println!("{label}  MISMATCH  {time:>12}");
+                    println!("  expected: {}", indent_lines(expected));
+                    println!("  got:      {}", indent_lines(&answer.value));
+                    mismatches += 1;
+                }
+                None => {
+                    println!("{label}  missing   {time:>12}");
+                    println!("  got:      {}", indent_lines(&answer.value));
+                    missing += 1;
+                    if record {
+                        db.insert(Entry {
+                            day,
+                            part: answer.part,
+                            input: input.clone(),
+                            answer: answer.value,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    println!(
+        "\n{nb_ok} ok, {mismatches} mismatched, {missing} missing, {failures} failed in {}",
+        format_duration(total)
+    );
+
+    if record && missing > 0 {
+        match db.save(path) {
+            Ok(()) => println!("recorded {missing} answers in {}", path.display()),
+            Err(error) => {
+                eprintln!("error: {error}");
+                return false;
+            }
+        }
+    }
+    mismatches == 0 && failures == 0
+}
+
+/// Benchmarks `days`, saves their timings in `save` and tells whether none of them is
+/// slower than in `baseline`.
+fn run_bench(
+    days: &[u8],
+    source: &Source,
+    runs: usize,
+    save: &Path,
+    baseline: &Path,
+    threshold: f64,
+) -> bool {
+    if cfg!(debug_assertions) {
+        eprintln!("warning: timings of a debug build, run with --release");
+    }
+    let (before, mut after) = match (BenchReport::load(baseline), BenchReport::load(save)) {
+        (Ok(before), Ok(after)) => (before, after),
+        (Err(error), _) | (_, Err(error)) => {
+            eprintln!("error: {error}");
+            return false;
+        }
+    };
+    let mut measured = BenchReport::default();
+    let mut failures = 0;
+    for &day in days {
+        let Some(solver) = solver(day) else {
+            eprintln!("error: no solution for day {day}");
+            failures += 1;
+            continue;
+        };
+        let result = input::load(day, source)
+            .map_err(|error| error.to_string())
+            .and_then(|data| {
+                bench::measure(day, solver.as_ref(), &data, runs)
+                    .map_err(|error| error.diagnostic(&data))
+            });
+        match result {
+            Ok(timings) => measured.update(timings),
+            Err(error) => {
+                eprintln!("error: {error}");
+                failures += 1;
+            }
+        }
+    }
+
+    let changes = bench::compare(&before, &measured);
+    let cell = |day: u8, step: Step| {
+        let time = format_duration(measured.get(day).unwrap().time(step));
+        match changes.iter().find(|c| c.day == day && c.step == step) {
+            Some(change) if change.is_regression(threshold) => {
+                format!("{time} ({:+.0}%) !", change.percent())
+            }
+            Some(change) => format!("{time} ({:+.0}%)  ", change.percent()),
+            None => time,
+        }
+    };
+    let mut rows: Vec<[String; 4]> = vec![["Day", "Parse", "Part 1", "Part 2"].map(String::from)];
+    rows.extend(measured.days.iter().map(|t| {
+        [
+            format!("{:02}", t.day),
+            cell(t.day, Step::Parse),
+            cell(t.day, Step::Part1),
+            cell(t.day, Step::Part2),
+        ]
+    }));
+    print_table(&rows, "<>>>");
+
+    let regressions: Vec<&Change> = changes
+        .iter()
+        .filter(|c| c.is_regression(threshold))
+        .collect();
+    if !regressions.is_empty() {
+        println!(
+            "\nslower by more than {threshold}% than {}:",
+            baseline.display()
+        );
+        for change in &regressions {
+            println!(
+                "  day {:02} {}: {} -> {}",
+                change.day,
+                change.step,
+                format_duration(change.before),
+                format_duration(change.after)
+            );
+        }
+    }
+
+    for timings in measured.days {
+        after.update(timings);
+    }
+    if let Err(error) = after.save(save) {
+        eprintln!("error: {error}");
+        return false;
+    }
+    regressions.is_empty() && failures == 0
+}
+
+/// Lines after the first one of a multi-line answer, aligned below it.
+fn indent_lines(answer: &str) -> String {
+    answer.replace('\n', "\n            ")
+}
+
+fn print_records(day: u8, data: &str, solved: &Solved) {
+    let input = answers::input_hash(data);
+    for answer in &solved.answers {
+        let record = Record::new(day, &input, answer);
+        println!("{}", serde_json::to_string(&record).unwrap());
+    }
+}
+
+/// One row per day; multi-line answers don't fit in a cell so they are printed below the table,
+/// as well as the days that couldn't be solved.
+fn print_summary(results: &[(u8, Result<Solved, String>)]) {
+    let cell = |answer: &Answer| {
+        if answer.value.contains('\n') {
+            String::from("(see below)")
+        } else {
+            answer.value.clone()
+        }
+    };
+    let mut rows: Vec<[String; 3]> = vec![[
+        String::from("Day"),
+        String::from("Part 1"),
+        String::from("Part 2"),
+    ]];
+    rows.extend(results.iter().map(|(day, solved)| match solved {
+        Ok(solved) => [
+            format!("{day:02}"),
+            cell(&solved.answers[0]),
+            cell(&solved.answers[1]),
+        ],
+        Err(_) => [format!("{day:02}"), String::from("(failed)"), String::new()],
+    }));
+    print_table(&rows, "<<<");
+
+    for (day, solved) in results {
+        match solved {
+            Ok(solved) => {
+                for answer in solved.answers.iter().filter(|a| a.value.contains('\n')) {
+                    println!();
+                    print_answer(*day, answer);
+                }
+            }
+            Err(error) => eprintln!("\nerror: {error}"),
+        }
+    }
+}
+
+/// The times of the solved days, the slowest first, with the time they took together and the
+/// CPU time of the process meanwhile.
+fn print_timings(
+    results: &[(u8, Result<Solved, String>)],
+    wall_time: Duration,
+    cpu_time: Option<Duration>,
+    threads: usize,
+) {
+    let mut solved: Vec<(u8, &Solved)> = results
+        .iter()
+        .filter_map(|(day, solved)| Some((*day, solved.as_ref().ok()?)))
+        .collect();
+    solved.sort_by_key(|&(day, solved)| (std::cmp::Reverse(solved.total_time()), day));
+
+    let mut rows: Vec<[String; 5]> = vec![[
+        String::from("Day"),
+        String::from("Parse"),
+        String::from("Part 1"),
+        String::from("Part 2"),
+        String::from("Total"),
+    ]];
+    rows.extend(solved.iter().map(|(day, solved)| {
+        [
+            format!("{day:02}"),
+            format_duration(solved.parse_time),
+            format_elapsed(&solved.answers[0]),
+            format_elapsed(&solved.answers[1]),
+            format_duration(solved.total_time()),
+        ]
+    }));
+    println!();
+    print_table(&rows, "<>>>>");
+    let threads = if threads > 1 {
+        format!("{threads} threads")
+    } else {
+        String::from("1 thread")
+    };
+    let cpu_time = cpu_time.map_or(String::new(), |cpu_time| {
+        format!(", CPU {}", format_duration(cpu_time))
+    });
+    println!(
+        "\nwall-clock {} on {threads}{cpu_time}",
+        format_duration(wall_time)
+    );
+}
+
+/// The memory used by each step of the days, from the days that allocate the most.
+fn print_memory(solved: &[(u8, &Solved)]) {
+    let allocated = |solved: &Solved| -> u64 {
+        let answers = solved.answers.iter().map(|answer| answer.memory);
+        std::iter::once(solved.parse_memory)
+            .chain(answers)
+            .flatten()
+            .map(|memory| memory.allocated)
+            .sum()
+    };
+    let mut solved = solved.to_vec();
+    solved.sort_by_key(|&(day, solved)| (std::cmp::Reverse(allocated(solved)), day));
+
+    let mut rows: Vec<[String; 6]> = vec![[
+        String::from("Day"),
+        String::from("Step"),
+        String::from("Time"),
+        String::from("Allocations"),
+        String::from("Allocated"),
+        String::from("Heap peak"),
+    ]];
+    let row = |day: u8, step: String, time: Duration, memory: Option<Memory>| {
+        let memory = memory.unwrap_or_default();
+        [
+            format!("{day:02}"),
+            step,
+            format_duration(time),
+            memory.allocations.to_string(),
+            format_bytes(memory.allocated),
+            format_bytes(memory.heap_peak),
+        ]
+    };
+    for (day, solved) in solved {
+        rows.push(row(
+            day,
+            String::from("parse"),
+            solved.parse_time,
+            solved.parse_memory,
+        ));
+        for answer in &solved.answers {
+            let step = format!("part {}", answer.part);
+            rows.push(row(day, step, answer.elapsed, answer.memory));
+        }
+    }
+    println!();
+    print_table(&rows, "<<>>>>");
+    if let Some(bytes) = usage::peak_resident() {
+        println!(
+            "\npeak resident memory of the process: {}",
+            format_bytes(bytes)
+        );
+    }
+}
+
+/// The answers of a day on each profile, marked when they are known to be right or wrong.
+fn print_comparison(day: u8, runs: &[ProfileRun]) {
+    let cell = |checked: &Checked| {
+        let value = if checked.answer.value.contains('\n') {
+            "(see below)"
+        } else {
+            &checked.answer.value
+        };
+        match &checked.expected {
+            Some(expected) if *expected == checked.answer.value => format!("{value} ok"),
+            Some(expected) if !expected.contains('\n') => {
+                format!("{value} WRONG, expected {expected}")
+            }
+            Some(_) => format!("{value} WRONG"),
+            None => String::from(value),
+        }
+    };
+    let parts: Vec<Part> = runs
+        .iter()
+        .find_map(|run| run.outcome.as_ref().ok())
+        .map_or(vec![], |checked| {
+            checked.iter().map(|c| c.answer.part).collect()
+        });
+
+    let mut header = vec![String::from("Profile")];
+    header.extend(parts.iter().map(|part| format!("Part {part}")));
+    header.push(String::from("Time"));
+    let mut rows = vec![header];
+    rows.extend(runs.iter().map(|run| {
+        let mut row = vec![run.profile.clone()];
+        match &run.outcome {
+            Ok(checked) => {
+                row.extend(checked.iter().map(cell));
+                row.push(format_duration(run.elapsed));
+            }
+            Err(_) => {
+                row.push(String::from("(failed)"));
+                row.resize(parts.len() + 2, String::new());
+            }
+        }
+        row
+    }));
+    // the time on the right, like the other numbers
+    print_table(&rows, &format!("{}>", "<".repeat(parts.len() + 1)));
+
+    for run in runs {
+        match &run.outcome {
+            Ok(checked) => {
+                for checked in checked.iter().filter(|c| c.answer.value.contains('\n')) {
+                    println!("\n{}:", run.profile);
+                    print_answer(day, &checked.answer);
+                }
+            }
+            Err(error) => eprintln!("\n{}: error: {error}", run.profile),
+        }
+    }
+}
+
+/// Prints `rows` in columns as wide as their widest cell, the header being the first row.
+/// `align` has a `<` for each column aligned to the left, or a `>` for the ones aligned to
+/// the right, like the numbers.
+fn print_table<R: AsRef<[String]>>(rows: &[R], align: &str) {
+    let mut widths = vec![0; align.len()];
+    for row in rows {
+        for (width, value) in widths.iter_mut().zip(row.as_ref()) {
+            *width = (*width).max(value.len());
+        }
+    }
+    for row in rows {
+        let cells: Vec<String> = row
+            .as_ref()
+            .iter()
+            .zip(widths.iter().zip(align.chars()))
+            .map(|(value, (&width, align))| match align {
+                '>' => format!("{value:>width$}"),
+                _ => format!("{value:<width$}"),
+            })
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    }
+}
+
+fn format_elapsed(answer: &Answer) -> String {
+    if answer.cached {
+        String::from("cached")
+    } else {
+        format_duration(answer.elapsed)
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{bytes} B"),
+        1024..1_048_576 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    if duration.as_secs() > 0 {
+        format!("{:.2}s", duration.as_secs_f64())
+    } else {
+        format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+    }
+}
@@ -0,0 +1,246 @@
+//! Answers already computed, kept on disk so that solving the days again only solves the
+//! ones whose input or solution changed.
+//!
+//! An answer is kept for a day, a part and the hash of an input, with the
+//! [`Solution::VERSION`](crate::Solution::VERSION) of the day: bumping it makes the day solved
+//! again.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answers::{self, load_json, part_number, save_json, JsonFileError};
+use crate::parse::ParseError;
+use crate::runner::{Answer, Part, Solved, Solver};
+
+/// In the build directory, as it is only worth keeping along with the binary.
+pub const DEFAULT_PATH: &str = "target/answers-cache.json";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    day: u8,
+    #[serde(with = "part_number")]
+    part: Part,
+    input: String,
+    version: u32,
+    answer: String,
+}
+
+/// The answers already computed, shared by the days solved side by side.
+#[derive(Debug, Default)]
+pub struct Cache {
+    entries: Mutex<Vec<Entry>>,
+    changed: AtomicBool,
+}
+
+impl Cache {
+    /// Loads the answers from `path`; a missing file is an empty cache.
+    pub fn load(path: &Path) -> Result<Self, JsonFileError> {
+        Ok(Cache {
+            entries: Mutex::new(load_json(path)?),
+            changed: AtomicBool::new(false),
+        })
+    }
+
+    /// Saves the answers to `path`, if new ones were computed since loaded.
+    pub fn save(&self, path: &Path) -> Result<(), JsonFileError> {
+        if !self.changed.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        save_json(path, &*self.entries.lock().unwrap())
+    }
+
+    /// Removes the cache at `path`, and tells whether there was one.
+    pub fn clear(path: &Path) -> Result<bool, JsonFileError> {
+        match std::fs::remove_file(path) {
+            Ok(()) => Ok(true),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(source) => Err(JsonFileError::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    fn get(&self, day: u8, part: Part, input: &str, version: u32) -> Option<String> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|e| e.day == day && e.part == part && e.input == input && e.version == version)
+            .map(|e| e.answer.clone())
+    }
+
+    /// Keeps an answer, instead of the one of another version of the solution.
+    fn insert(&self, entry: Entry) {
+        let mut entries = self.entries.lock().unwrap();
+        let key = |e: &Entry| (e.day, e.part, e.input.clone());
+        match entries.binary_search_by_key(&key(&entry), key) {
+            Ok(idx) => entries[idx] = entry,
+            Err(idx) => entries.insert(idx, entry),
+        }
+        self.changed.store(true, Ordering::SeqCst);
+    }
+
+    /// Like [`Solver::solve`] (or [`Solver::solve_parallel`]), but the answers already
+    /// computed come from the cache, and the input is only parsed when some are not.
+    pub fn solve(
+        &self,
+        day: u8,
+        solver: &dyn Solver,
+        data: &str,
+        parts: &[Part],
+        parallel: bool,
+    ) -> Result<Solved, ParseError> {
+        let input = answers::input_hash(data);
+        let version = solver.version();
+        let cached: Vec<Option<Answer>> = parts
+            .iter()
+            .map(|&part| {
+                let value = self.get(day, part, &input, version)?;
+                Some(Answer {
+                    part,
+                    value,
+                    elapsed: Duration::ZERO,
+                    cached: true,
+                    memory: None,
+                })
+            })
+            .collect();
+        let missing: Vec<Part> = parts
+            .iter()
+            .zip(&cached)
+            .filter(|(_, answer)| answer.is_none())
+            .map(|(&part, _)| part)
+            .collect();
+
+        let mut solved = match missing.as_slice() {
+            [] => Solved {
+                parse_time: Duration::ZERO,
+                parse_memory: None,
+                answers: vec![],
+            },
+            missing if parallel => solver.solve_parallel(data, missing)?,
+            missing => solver.solve(data, missing)?,
+        };
+        for answer in &solved.answers {
+            self.insert(Entry {
+                day,
+                part: answer.part,
+                input: input.clone(),
+                version,
+                answer: answer.value.clone(),
+            });
+        }
+        let mut computed = std::mem::take(&mut solved.answers).into_iter();
+        solved.answers = cached
+            .into_iter()
+            .map(|answer| answer.unwrap_or_else(|| computed.next().unwrap()))
+            .collect();
+        Ok(solved)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Cache;
+    use crate::runner::{solver, Part, Solver};
+    use crate::stand_in::temp_dir;
+    use crate::Solution;
+
+    /// Day 07 with another version of its solution.
+    struct NextVersion;
+
+    impl Solution for NextVersion {
+        const DAY: u8 = 7;
+        const VERSION: u32 = 2;
+        type Input = <crate::day07::Day07 as Solution>::Input;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(&self, data: &str) -> Result<Self::Input, crate::parse::ParseError> {
+            crate::day07::Day07.parse(data)
+        }
+
+        fn part1(&self, input: &Self::Input) -> usize {
+            crate::day07::Day07.part1(input) + 1
+        }
+
+        fn part2(&self, input: &Self::Input) -> usize {
+            crate::day07::Day07.part2(input) + 1
+        }
+    }
+
+    fn values(cache: &Cache, solver: &dyn Solver, data: &str) -> Vec<(String, bool)> {
+        cache
+            .solve(7, solver, data, &[Part::Two, Part::One], false)
+            .unwrap()
+            .answers
+            .into_iter()
+            .map(|answer| (answer.value, answer.cached))
+            .collect()
+    }
+
+    #[test]
+    fn answers_are_kept_for_their_input_and_version() {
+        let path = temp_dir("cache").join("cache.json");
+        let data = include_str!("../inputs/day07.sample.txt");
+        let day07 = solver(7).unwrap();
+
+        let cache = Cache::load(&path).unwrap();
+        let solved = cache
+            .solve(7, day07.as_ref(), data, &[Part::One], true)
+            .unwrap();
+        assert!(!solved.answers[0].cached);
+        cache.save(&path).unwrap();
+
+        let cache = Cache::load(&path).unwrap();
+        assert_eq!(
+            values(&cache, day07.as_ref(), data),
+            vec![
+                (String::from("24933642"), false),
+                (String::from("95437"), true)
+            ]
+        );
+        let other = data.replace("14848514", "14848515");
+        assert!(values(&cache, day07.as_ref(), &other)
+            .iter()
+            .all(|(_, cached)| !cached));
+
+        let solved = cache
+            .solve(7, &NextVersion, data, &[Part::One], false)
+            .unwrap();
+        assert_eq!(
+            (solved.answers[0].value.as_str(), solved.answers[0].cached),
+            ("95438", false)
+        );
+        assert_eq!(
+            values(&cache, &NextVersion, data),
+            vec![
+                (String::from("24933643"), false),
+                (String::from("95438"), true)
+            ]
+        );
+
+        assert!(Cache::clear(&path).unwrap());
+        assert!(!Cache::clear(&path).unwrap());
+    }
+
+    #[test]
+    fn cached_answers_skip_parsing() {
+        let cache = Cache::default();
+        let day01 = solver(1).unwrap();
+        let data = include_str!("../inputs/day01.sample.txt");
+        cache
+            .solve(1, day01.as_ref(), data, &Part::BOTH, false)
+            .unwrap();
+        let solved = cache
+            .solve(1, day01.as_ref(), data, &Part::BOTH, false)
+            .unwrap();
+        assert!(solved.answers.iter().all(|answer| answer.cached));
+        assert_eq!(solved.parse_time, std::time::Duration::ZERO);
+    }
+}
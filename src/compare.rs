@@ -0,0 +1,128 @@
+//! One day solved on the inputs of several profiles, to catch the solutions that only work
+//! on the input they were written for.
+
+use std::panic::AssertUnwindSafe;
+use std::time::Duration;
+
+use crate::answers::{self, AnswerDb};
+use crate::input::{self, Source};
+use crate::runner::{panic_message, solver, Answer, Part};
+
+/// An answer, with the known-good one for the same input if there is one.
+#[derive(Debug, Clone)]
+pub struct Checked {
+    pub answer: Answer,
+    pub expected: Option<String>,
+}
+
+impl Checked {
+    pub fn is_wrong(&self) -> bool {
+        self.expected
+            .as_ref()
+            .is_some_and(|expected| *expected != self.answer.value)
+    }
+}
+
+/// What a day gave on the input of a profile.
+#[derive(Debug, Clone)]
+pub struct ProfileRun {
+    pub profile: String,
+    /// the answers, or why there are none: no input, an input that can't be parsed, or a
+    /// solution that panicked on it
+    pub outcome: Result<Vec<Checked>, String>,
+    pub elapsed: Duration,
+}
+
+impl ProfileRun {
+    /// Whether the solution failed or gave a wrong answer on this input.
+    pub fn is_failure(&self) -> bool {
+        match &self.outcome {
+            Ok(checked) => checked.iter().any(Checked::is_wrong),
+            Err(_) => true,
+        }
+    }
+}
+
+/// Solves `parts` of `day` on the input of `profile` read from `source`, and checks the
+/// answers against `db`. A panic of the solution is a failure of this input only.
+pub fn run(day: u8, parts: &[Part], profile: &str, source: &Source, db: &AnswerDb) -> ProfileRun {
+    let failed = |error| ProfileRun {
+        profile: String::from(profile),
+        outcome: Err(error),
+        elapsed: Duration::ZERO,
+    };
+    let Some(solver) = solver(day) else {
+        return failed(format!("no solution for day {day}"));
+    };
+    let data = match input::load(day, source) {
+        Ok(data) => data,
+        Err(error) => return failed(error.to_string()),
+    };
+    let solved = match std::panic::catch_unwind(AssertUnwindSafe(|| solver.solve(&data, parts))) {
+        Ok(Ok(solved)) => solved,
+        Ok(Err(error)) => return failed(error.diagnostic(&data)),
+        Err(panic) => return failed(format!("panicked: {}", panic_message(&*panic))),
+    };
+    let input = answers::input_hash(&data);
+    ProfileRun {
+        profile: String::from(profile),
+        elapsed: solved.total_time(),
+        outcome: Ok(solved
+            .answers
+            .into_iter()
+            .map(|answer| Checked {
+                expected: db.get(day, answer.part, &input).map(String::from),
+                answer,
+            })
+            .collect()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::run;
+    use crate::answers::{input_hash, AnswerDb, Entry};
+    use crate::input::Source;
+    use crate::runner::Part;
+    use std::path::PathBuf;
+
+    #[test]
+    fn answers_are_checked_against_their_input() {
+        let sample = PathBuf::from("inputs/day07.sample.txt");
+        let input = input_hash(include_str!("../inputs/day07.sample.txt"));
+        let mut db = AnswerDb::default();
+        for (part, answer) in [(Part::One, "95437"), (Part::Two, "24933641")] {
+            db.insert(Entry {
+                day: 7,
+                part,
+                input: input.clone(),
+                answer: String::from(answer),
+            });
+        }
+
+        let run = run(7, &Part::BOTH, "sample", &Source::File(sample), &db);
+        let checked = run.outcome.as_ref().unwrap();
+        assert!(!checked[0].is_wrong());
+        assert!(checked[1].is_wrong());
+        assert!(run.is_failure());
+    }
+
+    #[test]
+    fn missing_or_invalid_inputs_fail_alone() {
+        let db = AnswerDb::default();
+        let missing = Source::Profile(String::from("nobody"));
+        let run_missing = run(7, &Part::BOTH, "nobody", &missing, &db);
+        assert!(run_missing
+            .outcome
+            .unwrap_err()
+            .contains("inputs/nobody/day07.txt"));
+
+        let other_day = Source::File(PathBuf::from("inputs/day09.sample1.txt"));
+        assert!(run(7, &Part::BOTH, "other", &other_day, &db).is_failure());
+
+        let sample = Source::File(PathBuf::from("inputs/day08.sample.txt"));
+        let run_sample = run(8, &[Part::One], "sample", &sample, &db);
+        assert!(!run_sample.is_failure());
+        assert_eq!(run_sample.outcome.unwrap()[0].answer.value, "21");
+    }
+}
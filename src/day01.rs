@@ -0,0 +1,59 @@
+use crate::parse::{self, number, Expected, ParseError};
+use crate::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    /// calories carried by each elf, in reverse order
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = u128;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        // a blank line separates the inventories of two elves
+        let mut elves_calories: Vec<usize> = parse::groups(data, |inventory| {
+            inventory.iter().try_fold(0, |total: usize, &(idx, line)| {
+                let error = |error| ParseError::new(Self::DAY, idx, error);
+                let calories = number::<usize>(line, line).map_err(error)?;
+                total
+                    .checked_add(calories)
+                    .ok_or_else(|| error(Expected::at(1, "calories that fit in usize")))
+            })
+        })?;
+        if elves_calories.is_empty() {
+            let error = Expected::at(1, "the calories carried by an elf");
+            return Err(ParseError::new(Self::DAY, 0, error));
+        }
+
+        // reverse order
+        elves_calories.sort_by(|a, b| b.cmp(a));
+        Ok(elves_calories)
+    }
+
+    fn part1(&self, elves_calories: &Self::Input) -> usize {
+        elves_calories[0]
+    }
+
+    fn part2(&self, elves_calories: &Self::Input) -> u128 {
+        // wide enough for three elves carrying the most an elf can
+        elves_calories.iter().take(3).map(|&c| c as u128).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day01;
+    use crate::Solution;
+
+    #[test]
+    fn calories_that_overflow() {
+        let error = Day01.parse("18446744073709551615\n1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "calories that fit in usize");
+
+        let max = usize::MAX.to_string();
+        let input = Day01.parse(&format!("{max}\n\n{max}\n\n1\n")).unwrap();
+        assert_eq!(Day01.part2(&input), 2 * usize::MAX as u128 + 1);
+    }
+}
@@ -0,0 +1,123 @@
+use std::str::FromStr;
+
+use crate::parse::{self, Expected, ParseError};
+use crate::Solution;
+
+pub struct Day02;
+
+/// A line of the strategy guide: the second letter is either our move (part 1)
+/// or the expected outcome (part 2), so both readings are kept.
+#[derive(Copy, Clone)]
+pub struct Round {
+    opponent_move: Move,
+    our_move: Move,
+    expected_outcome: Outcome,
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Round>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, data, |line| {
+            let (opponent, ours) = parse::split_pair(line, " ", "a space between the two letters")?;
+            Ok(Round {
+                opponent_move: opponent
+                    .parse::<Move>()
+                    .map_err(|e| e.within(line, opponent))?,
+                our_move: ours.parse::<Move>().map_err(|e| e.within(line, ours))?,
+                expected_outcome: ours.parse::<Outcome>().map_err(|e| e.within(line, ours))?,
+            })
+        })
+    }
+
+    fn part1(&self, rounds: &Self::Input) -> usize {
+        rounds
+            .iter()
+            .map(|round| {
+                let outcome = outcome(round.opponent_move, round.our_move);
+                round.our_move as usize + outcome as usize
+            })
+            .sum()
+    }
+
+    fn part2(&self, rounds: &Self::Input) -> usize {
+        rounds
+            .iter()
+            .map(|round| {
+                let our_move = guess_move_to_play(round.expected_outcome, round.opponent_move);
+                round.expected_outcome as usize + our_move as usize
+            })
+            .sum()
+    }
+}
+
+fn outcome(op_move: Move, our_move: Move) -> Outcome {
+    match (op_move, our_move) {
+        (Move::Rock, Move::Rock) => Outcome::Draw,
+        (Move::Rock, Move::Paper) => Outcome::Win,
+        (Move::Rock, Move::Scissors) => Outcome::Loss,
+        (Move::Paper, Move::Rock) => Outcome::Loss,
+        (Move::Paper, Move::Paper) => Outcome::Draw,
+        (Move::Paper, Move::Scissors) => Outcome::Win,
+        (Move::Scissors, Move::Rock) => Outcome::Win,
+        (Move::Scissors, Move::Paper) => Outcome::Loss,
+        (Move::Scissors, Move::Scissors) => Outcome::Draw,
+    }
+}
+
+fn guess_move_to_play(expected_outcome: Outcome, opponent_move: Move) -> Move {
+    match expected_outcome {
+        Outcome::Loss => match opponent_move {
+            Move::Rock => Move::Scissors,
+            Move::Paper => Move::Rock,
+            Move::Scissors => Move::Paper,
+        },
+        Outcome::Draw => opponent_move,
+        Outcome::Win => match opponent_move {
+            Move::Rock => Move::Paper,
+            Move::Paper => Move::Scissors,
+            Move::Scissors => Move::Rock,
+        },
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Move {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl FromStr for Move {
+    type Err = Expected;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "X" => Ok(Move::Rock),
+            "B" | "Y" => Ok(Move::Paper),
+            "C" | "Z" => Ok(Move::Scissors),
+            _ => Err(Expected::at(1, "a move (A, B, C, X, Y or Z)")),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Outcome {
+    Win = 6,
+    Draw = 3,
+    Loss = 0,
+}
+
+impl FromStr for Outcome {
+    type Err = Expected;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Outcome::Loss),
+            "Y" => Ok(Outcome::Draw),
+            "Z" => Ok(Outcome::Win),
+            _ => Err(Expected::at(1, "an outcome (X, Y or Z)")),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use crate::parse::{Expected, ParseError};
+use crate::Solution;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        let mut rucksacks = vec![];
+        for (idx, line) in data.lines().enumerate() {
+            let error = |error| ParseError::new(Self::DAY, idx, error);
+            if let Some(column) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(error(Expected::at(column + 1, "an item type (a-z or A-Z)")));
+            }
+            if line.len() % 2 != 0 {
+                return Err(error(Expected::at(
+                    line.len() + 1,
+                    "as many items in both compartments",
+                )));
+            }
+            if misplaced_item(line).is_none() {
+                return Err(error(Expected::at(1, "an item type in both compartments")));
+            }
+            rucksacks.push(String::from(line));
+        }
+        if rucksacks.len() % 3 != 0 {
+            // groups of three elves are needed for part 2
+            return Err(ParseError::new(
+                Self::DAY,
+                rucksacks.len(),
+                Expected::at(1, "another rucksack to complete the group of three elves"),
+            ));
+        }
+        if let Some(group) = rucksacks.chunks(3).position(|group| badge(group).is_none()) {
+            return Err(ParseError::new(
+                Self::DAY,
+                group * 3 + 2,
+                Expected::at(1, "an item type carried by the three elves of the group"),
+            ));
+        }
+        Ok(rucksacks)
+    }
+
+    fn part1(&self, rucksacks: &Self::Input) -> usize {
+        // parse made sure that every rucksack has one
+        rucksacks
+            .iter()
+            .filter_map(|rucksack| misplaced_item(rucksack))
+            .map(to_points)
+            .sum()
+    }
+
+    fn part2(&self, rucksacks: &Self::Input) -> usize {
+        // parse made sure that every group has one
+        rucksacks.chunks(3).filter_map(badge).map(to_points).sum()
+    }
+}
+
+/// The item type found in both compartments of a rucksack.
+fn misplaced_item(rucksack: &str) -> Option<char> {
+    let (comp1, comp2) = rucksack.split_at(rucksack.len() / 2);
+    let set: HashSet<char> = comp1.chars().collect();
+    comp2.chars().find(|c| set.contains(c))
+}
+
+/// The item type carried by every elf of a group.
+fn badge(group: &[String]) -> Option<char> {
+    let (last, others) = group.split_last()?;
+    let sets: Vec<HashSet<char>> = others.iter().map(|items| items.chars().collect()).collect();
+    last.chars()
+        .find(|c| sets.iter().all(|set| set.contains(c)))
+}
+
+const POINTS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+fn to_points(item_type: char) -> usize {
+    match POINTS.find(item_type) {
+        Some(p) => p + 1,
+        None => 0,
+    }
+}
@@ -0,0 +1,46 @@
+use crate::parse::{self, number, Expected, ParseError};
+use crate::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    /// section bounds of each pair: `[min1, max1, min2, max2]`
+    type Input = Vec<[usize; 4]>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, data, |line| {
+            let (first, second) = parse::split_pair(line, ",", "a comma between the two ranges")?;
+            let [min1, max1] = section_range(line, first)?;
+            let [min2, max2] = section_range(line, second)?;
+            Ok([min1, max1, min2, max2])
+        })
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> usize {
+        pairs
+            .iter()
+            .filter(|&&[min1, max1, min2, max2]| {
+                (min1 <= min2 && max2 <= max1) || (min2 <= min1 && max1 <= max2)
+            })
+            .count()
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> usize {
+        pairs
+            .iter()
+            .filter(|&&[min1, max1, min2, max2]| {
+                (min1 <= min2 && min2 <= max1) || (min2 <= min1 && min1 <= max2)
+            })
+            .count()
+    }
+}
+
+/// Bounds of a range like `2-4`, a sub-slice of `line`.
+fn section_range(line: &str, range: &str) -> Result<[usize; 2], Expected> {
+    let (min, max) = parse::split_pair(range, "-", "a dash between the bounds of the range")
+        .map_err(|error| error.within(line, range))?;
+    Ok([number(line, min)?, number(line, max)?])
+}
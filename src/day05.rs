@@ -0,0 +1,311 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+
+use tracing::{debug, trace};
+
+use crate::parse::{column_of, number, Expected, ParseError};
+use crate::runner::Part;
+use crate::simulation::Simulation;
+use crate::Solution;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Puzzle;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = data.lines().collect();
+        Puzzle::create(&lines)
+    }
+
+    fn part1(&self, puzzle: &Self::Input) -> String {
+        let mut p = puzzle.clone();
+        debug!("ship before the moves:\n{p}");
+        for &command in &puzzle.commands {
+            p.apply(command, false);
+        }
+        trace!("ship after the moves of the CrateMover 9000:\n{p}");
+        p.top_letters()
+    }
+
+    fn part2(&self, puzzle: &Self::Input) -> String {
+        let mut p = puzzle.clone();
+        for &command in &puzzle.commands {
+            p.apply(command, true);
+        }
+        trace!("ship after the moves of the CrateMover 9001:\n{p}");
+
+        p.top_letters()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Command {
+    nb: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Command {
+    /// Parses `move <nb> from <stack> to <stack>`, knowing how many stacks are on the ship.
+    fn parse(line: &str, words: &[&str], nb_stacks: usize) -> Result<Self, Expected> {
+        let keyword = |idx: usize, keyword: &str| match words.get(idx) {
+            Some(&word) if word == keyword => Ok(()),
+            Some(&word) => Err(Expected::at(1, format!("`{keyword}`")).within(line, word)),
+            None => Err(Expected::at(line.len() + 1, format!("`{keyword}`"))),
+        };
+        let stack = |idx: usize| match words.get(idx) {
+            Some(&word) => match number::<usize>(line, word)? {
+                stack if (1..=nb_stacks).contains(&stack) => Ok(stack - 1),
+                _ if nb_stacks == 0 => Err(Expected::at(1, "the drawing of the stacks first")),
+                _ => Err(
+                    Expected::at(1, format!("a stack between 1 and {nb_stacks}"))
+                        .within(line, word),
+                ),
+            },
+            None => Err(Expected::at(line.len() + 1, "a stack")),
+        };
+
+        let nb = match words.get(1) {
+            Some(&word) => number::<usize>(line, word)?,
+            None => return Err(Expected::at(line.len() + 1, "a number of crates")),
+        };
+        keyword(2, "from")?;
+        let from = stack(3)?;
+        keyword(4, "to")?;
+        let to = stack(5)?;
+        if let Some(&word) = words.get(6) {
+            return Err(Expected::at(1, "the end of the line").within(line, word));
+        }
+        Ok(Command { nb, from, to })
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.nb,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    ship: Vec<VecDeque<char>>,
+    commands: Vec<Command>,
+}
+
+impl Puzzle {
+    pub fn create(input: &[&str]) -> Result<Self, ParseError> {
+        let mut ship: Vec<VecDeque<char>> = vec![];
+        let mut commands: Vec<Command> = vec![];
+        // line index and column of the number of crates of each command
+        let mut located: Vec<(usize, usize)> = vec![];
+
+        for (idx, line) in input.iter().enumerate() {
+            let words = line
+                .split_ascii_whitespace()
+                .filter(|l| !l.is_empty())
+                .collect::<Vec<&str>>();
+            if words.is_empty() {
+                continue;
+            }
+            if words[0] == "move" {
+                // it's an instruction
+                let command = Command::parse(line, &words, ship.len())
+                    .map_err(|error| ParseError::new(Day05::DAY, idx, error))?;
+                located.push((idx, column_of(line, words[1])));
+                commands.push(command);
+            } else if line.contains('[') {
+                // it's a line containing a package
+                for (char_idx, ch) in line
+                    .chars()
+                    .enumerate()
+                    .skip(1)
+                    .step_by(4)
+                    .filter(|(_, ch)| *ch != ' ')
+                {
+                    let stack_idx = (char_idx - 1) / 4;
+                    while ship.len() < stack_idx + 1 {
+                        ship.push(VecDeque::new());
+                    }
+                    ship[stack_idx].push_front(ch);
+                }
+            } else if words.iter().all(|word| word.parse::<usize>().is_ok()) {
+                // the numbers below the stacks, which also count the empty ones
+                ship.resize(ship.len().max(words.len()), VecDeque::new());
+            }
+        }
+
+        // the crane can only take the crates that are there
+        let mut heights: Vec<usize> = ship.iter().map(VecDeque::len).collect();
+        for (command, &(idx, column)) in commands.iter().zip(&located) {
+            let height = heights[command.from];
+            if command.nb > height {
+                let expected = format!(
+                    "at most {height} crates, the ones on stack {}",
+                    command.from + 1
+                );
+                return Err(ParseError::new(
+                    Day05::DAY,
+                    idx,
+                    Expected::at(column, expected),
+                ));
+            }
+            heights[command.from] -= command.nb;
+            heights[command.to] += command.nb;
+        }
+        Ok(Self { ship, commands })
+    }
+
+    pub fn top_letters(&self) -> String {
+        self.ship.iter().filter_map(|stack| stack.back()).collect()
+    }
+
+    /// Moves the crates of `command` one at a time, like the CrateMover 9000, or all at once,
+    /// like the CrateMover 9001.
+    fn apply(&mut self, command: Command, all_at_once: bool) {
+        let from = &mut self.ship[command.from];
+        let mut moved = from.split_off(from.len() - command.nb);
+        if !all_at_once {
+            moved.make_contiguous().reverse();
+        }
+        self.ship[command.to].append(&mut moved);
+    }
+}
+
+/// The ship after each move of the crane.
+#[derive(Debug, Clone)]
+pub struct Crane {
+    puzzle: Puzzle,
+    moves: usize,
+    all_at_once: bool,
+}
+
+pub fn crane(puzzle: Puzzle, part: Part) -> Crane {
+    Crane {
+        puzzle,
+        moves: 0,
+        all_at_once: part == Part::Two,
+    }
+}
+
+impl Simulation for Crane {
+    fn step(&mut self) -> bool {
+        let Some(&command) = self.puzzle.commands.get(self.moves) else {
+            return false;
+        };
+        self.puzzle.apply(command, self.all_at_once);
+        self.moves += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        self.puzzle.to_string()
+    }
+
+    fn status(&self) -> String {
+        let last = match self.moves.checked_sub(1) {
+            Some(idx) => format!(" ({})", self.puzzle.commands[idx]),
+            None => String::new(),
+        };
+        format!(
+            "move {} of {}{last}, crates on top: {}",
+            self.moves,
+            self.puzzle.commands.len(),
+            self.puzzle.top_letters()
+        )
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let max_len = self.ship.iter().map(VecDeque::len).max().unwrap_or(0);
+        for y in (0..max_len).rev() {
+            for stack in self.ship.iter() {
+                if let Some(c) = stack.get(y) {
+                    write!(f, "[{c}] ")?;
+                } else {
+                    write!(f, "    ")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        for x in 1..=self.ship.len() {
+            write!(f, " {x}  ")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Day05, Puzzle};
+    use crate::parse::ParseError;
+    use crate::Solution;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    /// Up to 9 stacks, some of them empty, without moves.
+    fn ship() -> impl Strategy<Value = Puzzle> {
+        let stack = vec(proptest::char::range('A', 'Z'), 0..8);
+        vec(stack, 1..=9).prop_map(|stacks| Puzzle {
+            ship: stacks.into_iter().map(Into::into).collect(),
+            commands: vec![],
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn drawing_is_parsed_back(puzzle in ship()) {
+            prop_assert_eq!(Day05.parse(&puzzle.to_string()), Ok(puzzle));
+        }
+    }
+
+    #[test]
+    fn empty_stacks_on_the_right() {
+        let puzzle = Day05
+            .parse("[A]        \n 1   2   3 \n\nmove 1 from 1 to 3")
+            .unwrap();
+        assert_eq!(puzzle.ship.len(), 3);
+    }
+
+    #[test]
+    fn move_from_an_unknown_stack() {
+        let data = "[A] [B]\n 1   2\n\nmove 1 from 3 to 1";
+        assert_eq!(
+            Day05.parse(data).err(),
+            Some(ParseError {
+                day: 5,
+                line: 4,
+                column: 13,
+                expected: String::from("a stack between 1 and 2")
+            })
+        );
+    }
+
+    #[test]
+    fn move_more_crates_than_the_stack_holds() {
+        let data = "[A]\n 1   2\n\nmove 1 from 1 to 2\nmove 2 from 2 to 1";
+        assert_eq!(
+            Day05.parse(data).err(),
+            Some(ParseError {
+                day: 5,
+                line: 5,
+                column: 6,
+                expected: String::from("at most 1 crates, the ones on stack 2")
+            })
+        );
+    }
+}
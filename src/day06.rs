@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use tracing::{debug, trace};
+
+use crate::parse::{Expected, ParseError};
+use crate::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    /// the datastream buffer (first line of the input)
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        match data.lines().next() {
+            Some(line) if !line.is_empty() => {
+                // a start-of-message marker holds a start-of-packet one
+                if find_marker_index(line, MESSAGE_MARKER).is_none() {
+                    let expected =
+                        format!("{MESSAGE_MARKER} different characters in a row, for a marker");
+                    return Err(ParseError::new(
+                        Self::DAY,
+                        0,
+                        Expected::at(line.len() + 1, expected),
+                    ));
+                }
+                Ok(String::from(line))
+            }
+            _ => Err(ParseError::new(
+                Self::DAY,
+                0,
+                Expected::at(1, "a datastream buffer"),
+            )),
+        }
+    }
+
+    fn part1(&self, line: &Self::Input) -> usize {
+        // parse made sure that there is one
+        find_marker_index(line, PACKET_MARKER).unwrap_or_default()
+    }
+
+    fn part2(&self, line: &Self::Input) -> usize {
+        // parse made sure that there is one
+        find_marker_index(line, MESSAGE_MARKER).unwrap_or_default()
+    }
+}
+
+/// Sizes of the start-of-packet and start-of-message markers.
+const PACKET_MARKER: usize = 4;
+const MESSAGE_MARKER: usize = 14;
+
+fn find_marker_index(word: &str, marker_size: usize) -> Option<usize> {
+    let mut last_seen_chars = HashMap::new();
+    let mut last_duplicate_index = None;
+
+    trace!(marker_size, "datastream: {word}");
+    for (i, ch) in word.chars().enumerate() {
+        let last_time_we_saw_ch = last_seen_chars.insert(ch, i);
+        last_duplicate_index = last_duplicate_index.max(last_time_we_saw_ch);
+        // the marker is the `marker_size` characters up to `i`, after the last duplicate
+        let first_possible_end = last_duplicate_index.map_or(marker_size - 1, |d| d + marker_size);
+        if i >= first_possible_end {
+            let marker: String = word
+                .chars()
+                .skip(i + 1 - marker_size)
+                .take(marker_size)
+                .collect();
+            debug!(marker_size, "marker: {marker}");
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_marker_index, Day06};
+    use crate::Solution;
+
+    #[test]
+    fn part_1_first_sample() {
+        assert_eq!(
+            find_marker_index("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn part_1_second_sample() {
+        assert_eq!(
+            find_marker_index("bvwbjplbgvbhsrlpgdmjqwftvncz", 4),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn part_1_third_sample() {
+        assert_eq!(
+            find_marker_index("nppdvjthqldpwncqszvftbrmjlhg", 4),
+            Some(6)
+        );
+    }
+    #[test]
+    fn part_1_fourth_sample() {
+        assert_eq!(
+            find_marker_index("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4),
+            Some(10)
+        );
+    }
+    #[test]
+    fn part_1_fifth_sample() {
+        assert_eq!(
+            find_marker_index("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4),
+            Some(11)
+        );
+    }
+
+    #[test]
+    fn marker_at_the_start() {
+        assert_eq!(find_marker_index("abcd", 4), Some(4));
+        assert_eq!(find_marker_index("abca", 4), None);
+    }
+
+    #[test]
+    fn buffer_without_marker() {
+        let error = Day06.parse("abc").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+        assert!(Day06.parse("abcdefghijklmn").is_ok());
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::parse::{number, Expected, ParseError};
+use crate::Solution;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    /// total size of each directory, keyed by its path
+    type Input = HashMap<Vec<String>, usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = data.lines().collect();
+        parse_input(&lines)
+    }
+
+    fn part1(&self, size_map: &Self::Input) -> usize {
+        size_map.values().filter(|v| **v < 100_000).sum()
+    }
+
+    fn part2(&self, size_map: &Self::Input) -> usize {
+        // parse made sure that the files fit on the disk
+        let used_space = size_map[&vec![String::from("/")]];
+        let unused_space = DISK_SPACE - used_space;
+        if unused_space >= UPDATE_SPACE {
+            // nothing to delete
+            return 0;
+        }
+        let needed_space = UPDATE_SPACE - unused_space;
+
+        // the root directory is always big enough
+        size_map
+            .values()
+            .copied()
+            .filter(|v| *v > needed_space)
+            .min()
+            .unwrap_or(used_space)
+    }
+}
+
+const DISK_SPACE: usize = 70_000_000;
+const UPDATE_SPACE: usize = 30_000_000;
+
+enum LineType {
+    CdCommand { target_directory: String },
+    LsCommand,
+    DirLine { directory_name: String },
+    FileSizeLine { size: usize },
+}
+
+impl FromStr for LineType {
+    type Err = Expected;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = |prefix: &str| match &s[prefix.len()..] {
+            "" => Err(Expected::at(prefix.len() + 1, "a directory name")),
+            name => Ok(String::from(name)),
+        };
+        match s {
+            _ if s.starts_with("$ cd ") => Ok(LineType::CdCommand {
+                target_directory: name("$ cd ")?,
+            }),
+            "$ ls" => Ok(LineType::LsCommand),
+            _ if s.starts_with("$ ") => Err(Expected::at(3, "a `cd` or `ls` command")),
+            _ if s.starts_with("dir ") => Ok(LineType::DirLine {
+                directory_name: name("dir ")?,
+            }),
+            _ => {
+                let size = s.split(' ').next().unwrap();
+                Ok(LineType::FileSizeLine {
+                    size: number(s, size)
+                        .map_err(|_| Expected::at(1, "a command, a directory or a file size"))?,
+                })
+            }
+        }
+    }
+}
+
+fn parse_input(lines: &[&str]) -> Result<HashMap<Vec<String>, usize>, ParseError> {
+    let slash = String::from("/");
+    let back = String::from("..");
+    let mut sizes: HashMap<Vec<String>, usize> = HashMap::new();
+    let slash_key = vec![slash.clone()];
+    sizes.insert(slash_key.clone(), 0);
+    let mut current_path: Vec<String> = vec![];
+    let mut used_space = 0;
+
+    for (idx, &line) in lines.iter().enumerate() {
+        let line_type = line
+            .parse::<LineType>()
+            .map_err(|error| ParseError::new(Day07::DAY, idx, error))?;
+        match line_type {
+            LineType::CdCommand { target_directory } => {
+                if target_directory.eq(&back) {
+                    if current_path.len() <= 1 {
+                        let expected = "a directory name, `..` would leave the root directory";
+                        let error = Expected::at("$ cd ".len() + 1, expected);
+                        return Err(ParseError::new(Day07::DAY, idx, error));
+                    }
+                    current_path.pop();
+                } else if target_directory.eq(&slash) {
+                    current_path = slash_key.clone();
+                } else {
+                    current_path.push(target_directory);
+                }
+            }
+            LineType::LsCommand => {}
+            LineType::DirLine { directory_name } => {
+                let mut new_dir_path = current_path.clone();
+                new_dir_path.push(directory_name);
+                sizes.insert(new_dir_path, 0);
+            }
+            LineType::FileSizeLine { size } => {
+                if size > DISK_SPACE - used_space {
+                    let expected = format!("a file that fits on the disk of {DISK_SPACE} bytes");
+                    return Err(ParseError::new(Day07::DAY, idx, Expected::at(1, expected)));
+                }
+                used_space += size;
+                for i in 0..(current_path.len()) {
+                    // update each path, even the ones we went into without listing them
+                    let path = &current_path[0..i + 1];
+                    match sizes.get_mut(path) {
+                        Some(dir_size) => *dir_size += size,
+                        None => {
+                            sizes.insert(path.to_vec(), size);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(sizes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day07;
+    use crate::Solution;
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day07
+            .parse(include_str!("../inputs/day07.sample.txt"))
+            .unwrap();
+        assert_eq!(Day07.part1(&input), 95437);
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day07
+            .parse(include_str!("../inputs/day07.sample.txt"))
+            .unwrap();
+        assert_eq!(Day07.part2(&input), 24933642);
+    }
+
+    #[test]
+    fn leaving_the_root_directory() {
+        let error = Day07.parse("$ cd /\n$ cd ..\n$ cd ..").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        let error = Day07
+            .parse("$ cd /\n$ ls\n40000000 a\n40000000 b")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+    }
+}
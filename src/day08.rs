@@ -0,0 +1,143 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Puzzle;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        let tree_heights = Grid::parse(Self::DAY, data, |_, ch| {
+            ch.to_digit(10).ok_or("a tree height (0-9)")
+        })?;
+        Ok(Puzzle { tree_heights })
+    }
+
+    fn part1(&self, p: &Self::Input) -> usize {
+        p.tree_heights
+            .positions()
+            .filter(|pos| p.visible(pos.x, pos.y))
+            .count()
+    }
+
+    fn part2(&self, p: &Self::Input) -> usize {
+        p.tree_heights
+            .positions()
+            .map(|pos| p.scenic_score(pos.x, pos.y))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+pub struct Puzzle {
+    tree_heights: Grid<u32>,
+}
+
+impl Puzzle {
+    /// A tree is visible when all the trees between it and an edge are smaller; trees on the
+    /// edge are always visible.
+    fn visible(&self, x: usize, y: usize) -> bool {
+        let height = self.tree_heights[Point::new(x, y)];
+        Direction::ALL.into_iter().any(|direction| {
+            self.tree_heights
+                .ray(Point::new(x, y), direction.delta())
+                .all(|pos| self.tree_heights[pos] < height)
+        })
+    }
+
+    /// Number of trees seen in a direction: up to the edge, or up to the first tree that is at
+    /// least as high (that tree included).
+    fn viewing_distance(&self, x: usize, y: usize, direction: Direction) -> usize {
+        let height = self.tree_heights[Point::new(x, y)];
+        let mut count = 0;
+        for pos in self.tree_heights.ray(Point::new(x, y), direction.delta()) {
+            count += 1;
+            if self.tree_heights[pos] >= height {
+                break;
+            }
+        }
+        count
+    }
+
+    fn scenic_score(&self, x: usize, y: usize) -> usize {
+        Direction::ALL
+            .into_iter()
+            .map(|direction| self.viewing_distance(x, y, direction))
+            .product()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day08;
+    use crate::Solution;
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day08
+            .parse(include_str!("../inputs/day08.sample.txt"))
+            .unwrap();
+        assert_eq!(Day08.part1(&input), 21);
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day08
+            .parse(include_str!("../inputs/day08.sample.txt"))
+            .unwrap();
+        assert_eq!(Day08.part2(&input), 8);
+    }
+
+    #[test]
+    fn scenic_score_1_1() {
+        let puzzle = Day08
+            .parse(include_str!("../inputs/day08.sample.txt"))
+            .unwrap();
+        assert_eq!(puzzle.scenic_score(1, 1), 1);
+    }
+
+    #[test]
+    fn scenic_score_1_2() {
+        let puzzle = Day08
+            .parse(include_str!("../inputs/day08.sample.txt"))
+            .unwrap();
+        assert_eq!(puzzle.scenic_score(1, 2), 6);
+    }
+
+    #[test]
+    fn scenic_score_1_3() {
+        let puzzle = Day08
+            .parse(include_str!("../inputs/day08.sample.txt"))
+            .unwrap();
+        assert_eq!(puzzle.scenic_score(1, 3), 1);
+    }
+
+    #[test]
+    fn scenic_score_2_1() {
+        let puzzle = Day08
+            .parse(include_str!("../inputs/day08.sample.txt"))
+            .unwrap();
+        assert_eq!(puzzle.scenic_score(2, 1), 4);
+    }
+
+    #[test]
+    fn scenic_score_2_3() {
+        let puzzle = Day08
+            .parse(include_str!("../inputs/day08.sample.txt"))
+            .unwrap();
+        assert_eq!(puzzle.scenic_score(2, 3), 8);
+    }
+
+    #[test]
+    fn scenic_score_4_3() {
+        let puzzle = Day08
+            .parse(include_str!("../inputs/day08.sample.txt"))
+            .unwrap();
+        assert_eq!(puzzle.scenic_score(4, 3), 0);
+    }
+}
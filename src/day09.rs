@@ -0,0 +1,406 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use tracing::trace;
+
+use crate::geometry::{Bounds, Direction, Point};
+use crate::parse::{self, number, Expected, ParseError};
+use crate::runner::Part;
+use crate::simulation::Simulation;
+use crate::Solution;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Command>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, data, str::parse::<Command>)
+    }
+
+    fn part1(&self, commands: &Self::Input) -> usize {
+        let mut grid = Grid::new(2);
+        for &command in commands {
+            grid.move_head(command);
+        }
+
+        grid.tail_tracks.len()
+    }
+
+    fn part2(&self, commands: &Self::Input) -> usize {
+        let mut grid = Grid::new(10);
+        for &command in commands {
+            grid.move_head(command);
+        }
+
+        grid.tail_tracks.len()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Command {
+    times: usize,
+    direction: Direction,
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let letter = match self.direction {
+            Direction::Up => 'U',
+            Direction::Down => 'D',
+            Direction::Right => 'R',
+            Direction::Left => 'L',
+        };
+        write!(f, "{letter} {}", self.times)
+    }
+}
+
+impl FromStr for Command {
+    type Err = Expected;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (letter, quantity) =
+            parse::split_pair(s, " ", "a space between the direction and the steps")?;
+
+        let direction = letter.parse::<Direction>()?;
+        let times = number(s, quantity)?;
+        Ok(Command { times, direction })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Grid {
+    head_tracks: HashSet<Point>,
+    tail_tracks: HashSet<Point>,
+    rope: Vec<Point>,
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some(bounds) = Bounds::of(self.head_tracks.iter().copied()) else {
+            return Ok(());
+        };
+        for y in bounds.min.y..=bounds.max.y {
+            for x in bounds.min.x..=bounds.max.x {
+                let point = Point::new(x, y);
+                if let Some(&head) = self.rope.first() {
+                    if head == point {
+                        write!(f, "H")?;
+                    } else if let Some((idx, _)) = self
+                        .rope
+                        .iter()
+                        .enumerate()
+                        .find(|(_, &knot)| knot == point)
+                    {
+                        write!(f, "{}", idx)?;
+                    } else if point == Point::default() {
+                        write!(f, "s")?;
+                    } else {
+                        write!(f, ".")?;
+                    }
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Grid {
+    fn new(rope_size: usize) -> Self {
+        let rope = vec![Point::default(); rope_size];
+        let mut tail_tracks = HashSet::new();
+        tail_tracks.insert(rope[rope.len() - 1]);
+        let head_tracks = tail_tracks.clone();
+        Self {
+            tail_tracks,
+            rope,
+            head_tracks,
+        }
+    }
+
+    fn move_head(&mut self, c: Command) {
+        trace!("== {c} ==");
+        for _ in 0..c.times {
+            self.move_head_once(c.direction);
+            trace!("\n{self}");
+        }
+    }
+
+    fn move_head_once(&mut self, d: Direction) {
+        let mut iter = self.rope.iter_mut();
+        if let Some(head) = iter.next() {
+            *head += d.delta();
+            self.head_tracks.insert(*head);
+            // adapting the rest of the rope
+            let mut point_to_follow = *head;
+            for rope_body_part in iter {
+                *rope_body_part = follow(*rope_body_part, point_to_follow);
+                point_to_follow = *rope_body_part;
+            }
+            self.tail_tracks.insert(point_to_follow);
+        }
+    }
+}
+
+/// The rope after each step of its head.
+#[derive(Debug, Clone)]
+pub struct Rope {
+    grid: Grid,
+    commands: Vec<Command>,
+    /// the command being followed, and how many steps of it were made
+    command: usize,
+    steps: usize,
+}
+
+pub fn rope(commands: Vec<Command>, part: Part) -> Rope {
+    let rope_size = if part == Part::One { 2 } else { 10 };
+    Rope {
+        grid: Grid::new(rope_size),
+        commands,
+        command: 0,
+        steps: 0,
+    }
+}
+
+impl Simulation for Rope {
+    fn step(&mut self) -> bool {
+        while let Some(command) = self.commands.get(self.command) {
+            if self.steps < command.times {
+                self.grid.move_head_once(command.direction);
+                self.steps += 1;
+                return true;
+            }
+            self.command += 1;
+            self.steps = 0;
+        }
+        false
+    }
+
+    fn render(&self) -> String {
+        self.grid.to_string()
+    }
+
+    fn status(&self) -> String {
+        let command = match self.commands.get(self.command) {
+            Some(command) => format!("{command} ({}/{}), ", self.steps, command.times),
+            None => String::new(),
+        };
+        format!(
+            "move {} of {}: {command}the tail visited {} positions",
+            (self.command + 1).min(self.commands.len()),
+            self.commands.len(),
+            self.grid.tail_tracks.len()
+        )
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+/// Moves `knot` so that it touches `target` again, diagonally if needed.
+fn follow(knot: Point, target: Point) -> Point {
+    if knot.chebyshev(target) < 2 {
+        // still touching
+        return knot;
+    }
+    knot + (target - knot).signum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{follow, Command, Day09, Direction, Grid, Point};
+    use crate::parse::{Expected, ParseError};
+    use crate::Solution;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn display_is_parsed_back(direction in 0..4usize, times in any::<usize>()) {
+            let command = Command { times, direction: Direction::ALL[direction] };
+            prop_assert_eq!(command.to_string().parse::<Command>(), Ok(command));
+        }
+    }
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day09
+            .parse(include_str!("../inputs/day09.sample1.txt"))
+            .unwrap();
+        assert_eq!(Day09.part1(&input), 13);
+    }
+
+    #[test]
+    fn parse_invalid_commands() {
+        assert_eq!(
+            "R4".parse::<Command>().err(),
+            Some(Expected::at(
+                3,
+                "a space between the direction and the steps"
+            ))
+        );
+        assert_eq!(
+            "X 4".parse::<Command>().err(),
+            Some(Expected::at(1, "a direction (R, L, U or D)"))
+        );
+        assert_eq!(
+            Day09.parse("R 4\nU -1").err(),
+            Some(ParseError {
+                day: 9,
+                line: 2,
+                column: 3,
+                expected: String::from("a number")
+            })
+        );
+    }
+
+    #[test]
+    fn tail_should_move_north_west() {
+        let mut grid = Grid::new(2);
+        grid.move_head(Command {
+            times: 1,
+            direction: Direction::Right,
+        });
+        grid.move_head(Command {
+            times: 2,
+            direction: Direction::Up,
+        });
+
+        assert_eq!(grid.rope, vec![Point::new(1, -2), Point::new(1, -1)]);
+        assert_eq!(grid.tail_tracks.len(), 2);
+        assert!(grid.tail_tracks.contains(&Point::new(0, 0)));
+        assert!(grid.tail_tracks.contains(&Point::new(1, -1)));
+    }
+
+    #[test]
+    fn part_2_sample1() {
+        let input = Day09
+            .parse(include_str!("../inputs/day09.sample1.txt"))
+            .unwrap();
+        assert_eq!(Day09.part2(&input), 1);
+    }
+
+    #[test]
+    fn part_2_sample2() {
+        let input = Day09
+            .parse(include_str!("../inputs/day09.sample2.txt"))
+            .unwrap();
+        assert_eq!(Day09.part2(&input), 36);
+    }
+
+    #[test]
+    fn follow_north() {
+        let point = Point::new(0, 0);
+        let target = Point::new(0, 2);
+        assert_eq!(follow(point, target), Point::new(0, 1));
+    }
+    #[test]
+    fn follow_south() {
+        let point = Point::new(0, 0);
+        let target = Point::new(0, -2);
+        assert_eq!(follow(point, target), Point::new(0, -1));
+    }
+
+    #[test]
+    fn follow_east() {
+        let point = Point::new(0, 0);
+        let target = Point::new(2, 0);
+        assert_eq!(follow(point, target), Point::new(1, 0));
+    }
+
+    #[test]
+    fn follow_west() {
+        let point = Point::new(0, 0);
+        let target = Point::new(-2, 0);
+        assert_eq!(follow(point, target), Point::new(-1, 0));
+    }
+
+    #[test]
+    fn follow_north_west_1() {
+        let point = Point::new(0, 0);
+        let target = Point::new(-2, 1);
+        assert_eq!(follow(point, target), Point::new(-1, 1));
+    }
+
+    #[test]
+    fn follow_north_west_2() {
+        let point = Point::new(0, 0);
+        let target = Point::new(-1, 2);
+        assert_eq!(follow(point, target), Point::new(-1, 1));
+    }
+
+    #[test]
+    fn follow_north_west_3() {
+        let point = Point::new(0, 0);
+        let target = Point::new(-2, 2);
+        assert_eq!(follow(point, target), Point::new(-1, 1));
+    }
+
+    #[test]
+    fn follow_north_east_1() {
+        let point = Point::new(0, 0);
+        let target = Point::new(2, 1);
+        assert_eq!(follow(point, target), Point::new(1, 1));
+    }
+
+    #[test]
+    fn follow_north_east_2() {
+        let point = Point::new(0, 0);
+        let target = Point::new(1, 2);
+        assert_eq!(follow(point, target), Point::new(1, 1));
+    }
+
+    #[test]
+    fn follow_north_east_3() {
+        let point = Point::new(0, 0);
+        let target = Point::new(2, 2);
+        assert_eq!(follow(point, target), Point::new(1, 1));
+    }
+
+    #[test]
+    fn follow_south_west_1() {
+        let point = Point::new(0, 0);
+        let target = Point::new(-2, -1);
+        assert_eq!(follow(point, target), Point::new(-1, -1));
+    }
+
+    #[test]
+    fn follow_south_west_2() {
+        let point = Point::new(0, 0);
+        let target = Point::new(-1, -2);
+        assert_eq!(follow(point, target), Point::new(-1, -1));
+    }
+
+    #[test]
+    fn follow_south_west_3() {
+        let point = Point::new(0, 0);
+        let target = Point::new(-2, -2);
+        assert_eq!(follow(point, target), Point::new(-1, -1));
+    }
+
+    #[test]
+    fn follow_south_east_1() {
+        let point = Point::new(0, 0);
+        let target = Point::new(2, -1);
+        assert_eq!(follow(point, target), Point::new(1, -1));
+    }
+
+    #[test]
+    fn follow_south_east_2() {
+        let point = Point::new(0, 0);
+        let target = Point::new(1, -2);
+        assert_eq!(follow(point, target), Point::new(1, -1));
+    }
+
+    #[test]
+    fn follow_south_east_3() {
+        let point = Point::new(0, 0);
+        let target = Point::new(2, -2);
+        assert_eq!(follow(point, target), Point::new(1, -1));
+    }
+}
@@ -0,0 +1,181 @@
+use std::str::FromStr;
+
+use crate::parse::{self, number, Expected, ParseError};
+use crate::simulation::Simulation;
+use crate::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Operation>;
+    /// wider than the X register, as it is multiplied by the cycle
+    type Part1 = i64;
+    /// the CRT screen, one line per row of pixels
+    type Part2 = String;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        let operations = parse::lines(Self::DAY, data, str::parse::<Operation>)?;
+        let mut x: i32 = 1;
+        for (idx, operation) in operations.iter().enumerate() {
+            if let Operation::AddX(num) = *operation {
+                x = x
+                    .checked_add(num)
+                    .filter(|x| x.abs() <= MAX_X)
+                    .ok_or_else(|| {
+                        let expected = format!("a value keeping X between -{MAX_X} and {MAX_X}");
+                        ParseError::new(Self::DAY, idx, Expected::at(6, expected))
+                    })?;
+            }
+        }
+        Ok(operations)
+    }
+
+    fn part1(&self, operations: &Self::Input) -> i64 {
+        signal_strength(&cycles(operations))
+    }
+
+    fn part2(&self, operations: &Self::Input) -> String {
+        draw(&cycles(operations))
+    }
+}
+
+/// Largest value of the X register accepted, far beyond the width of the screen.
+const MAX_X: i32 = 1_000_000;
+
+/// Sum of the signal strengths during the 20th cycle and every 40 cycles after.
+fn signal_strength(cycles: &[i32]) -> i64 {
+    cycles
+        .iter()
+        .enumerate()
+        .skip(19)
+        .step_by(40)
+        .map(|(idx, &x)| (idx as i64 + 1) * i64::from(x))
+        .sum()
+}
+
+/// The pixels drawn by the CRT during `cycles`, a row every 40 cycles.
+fn draw(cycles: &[i32]) -> String {
+    cycles
+        .chunks(40)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(String::new(), |mut acc, (crt_index, sprite_pos)| {
+                    let c = if crt_index as i32 == *sprite_pos - 1
+                        || crt_index as i32 == *sprite_pos
+                        || crt_index as i32 == *sprite_pos + 1
+                    {
+                        '#'
+                    } else {
+                        ' '
+                    };
+                    acc.push(c);
+                    acc
+                })
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// The screen after each cycle of the CPU.
+#[derive(Debug, Clone)]
+pub struct Crt {
+    cycles: Vec<i32>,
+    /// cycles done
+    cycle: usize,
+}
+
+pub fn crt(operations: &[Operation]) -> Crt {
+    Crt {
+        cycles: cycles(operations),
+        cycle: 0,
+    }
+}
+
+impl Simulation for Crt {
+    fn step(&mut self) -> bool {
+        if self.cycle == self.cycles.len() {
+            return false;
+        }
+        self.cycle += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        // the sprite, where the X register puts it for the next cycle
+        let sprite: String = match self.cycles.get(self.cycle) {
+            Some(&x) => (0..40)
+                .map(|column: i32| if (column - x).abs() <= 1 { '#' } else { '.' })
+                .collect(),
+            None => String::new(),
+        };
+        format!("{}\n\n{sprite}", draw(&self.cycles[..self.cycle]))
+    }
+
+    fn status(&self) -> String {
+        let x = self.cycles.get(self.cycle).or(self.cycles.last());
+        format!(
+            "cycle {} of {}, X={}, signal strength {}",
+            self.cycle,
+            self.cycles.len(),
+            x.unwrap_or(&1),
+            signal_strength(&self.cycles[..self.cycle])
+        )
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+/// value of the X register during each cycle, kept within `MAX_X` by parse
+fn cycles(operations: &[Operation]) -> Vec<i32> {
+    let mut x = 1;
+    operations
+        .iter()
+        .flat_map(|op| match *op {
+            Operation::AddX(num) => {
+                let old_x = x;
+                x += num;
+                vec![old_x, old_x]
+            }
+            Operation::Noop => {
+                vec![x]
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Operation {
+    AddX(i32),
+    Noop,
+}
+
+impl FromStr for Operation {
+    type Err = Expected;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "noop" => Ok(Operation::Noop),
+            _ if s.starts_with("addx ") => Ok(Operation::AddX(number(s, &s[5..])?)),
+            _ => Err(Expected::at(1, "an operation (noop or addx)")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day10;
+    use crate::Solution;
+
+    #[test]
+    fn x_register_out_of_range() {
+        let error = Day10.parse("addx 999999\nnoop\naddx 2").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
+        assert!(Day10.parse("addx 2147483647\n").is_err());
+        assert!(Day10.parse("addx -1000001\naddx 999999").is_ok());
+    }
+}
@@ -0,0 +1,467 @@
+use crate::parse::{self, column_of, number, Expected, Line, ParseError};
+use crate::runner::Part;
+use crate::simulation::Simulation;
+use crate::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        let (monkeys, targets) = parse_monkeys(data)?;
+        if monkeys.is_empty() {
+            let error = Expected::at(1, "`Monkey 0:`");
+            return Err(ParseError::new(Self::DAY, 0, error));
+        }
+        for (idx, line, target) in targets {
+            if number::<usize>(line, target).map_or(true, |target| target >= monkeys.len()) {
+                let expected = format!("a monkey between 0 and {}", monkeys.len() - 1);
+                let error = Expected::at(column_of(line, target), expected);
+                return Err(ParseError::new(Self::DAY, idx, error));
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> usize {
+        handle_puzzle(monkeys, 20, true)
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> usize {
+        handle_puzzle(monkeys, 10_000, false)
+    }
+}
+
+fn handle_puzzle(monkeys: &[Monkey], nb_rounds: usize, is_part_1: bool) -> usize {
+    let mut monkeys = monkeys.to_vec();
+    let keep_cool_modulo = keep_cool_modulo(&monkeys);
+    for _ in 0..nb_rounds {
+        monkeys = handle_round(monkeys, is_part_1, keep_cool_modulo);
+    }
+    monkey_business(&monkeys)
+}
+
+fn keep_cool_modulo(monkeys: &[Monkey]) -> usize {
+    // for part2, you multiple super big numbers so you have to find a number that will not change
+    // the results of all the tests for monkeys.
+    // staying in Z/nZ with n=product(divisors) will keep enough "range" to cover each divisibility tests for those divisors
+    // parse made sure that the product stays under MAX_MODULO
+    monkeys
+        .iter()
+        .map(|m| m.test.divisible_by)
+        .product::<usize>()
+}
+
+/// Largest product of the divisors, and largest operand, so that the worry levels kept in
+/// Z/3nZ can be multiplied without overflow.
+const MAX_MODULO: usize = 1 << 30;
+
+fn handle_round(mut monkeys: Vec<Monkey>, divide_by_3: bool, stay_cool: usize) -> Vec<Monkey> {
+    for i in 0..monkeys.len() {
+        monkeys = handle_turn_of_monkey_x(monkeys, i, divide_by_3, stay_cool);
+    }
+    monkeys
+}
+
+fn monkey_business(monkeys: &[Monkey]) -> usize {
+    let mut inspected_items = monkeys
+        .iter()
+        .map(|monkey| monkey.nb_inspected_items)
+        .collect::<Vec<usize>>();
+    inspected_items.sort_by(|a, b| b.cmp(a));
+    inspected_items.iter().take(2).product()
+}
+
+/// The items held by the monkeys after each round.
+#[derive(Debug, Clone)]
+pub struct Rounds {
+    monkeys: Vec<Monkey>,
+    round: usize,
+    nb_rounds: usize,
+    divide_by_3: bool,
+    keep_cool_modulo: usize,
+}
+
+pub fn rounds(monkeys: Vec<Monkey>, part: Part) -> Rounds {
+    Rounds {
+        keep_cool_modulo: keep_cool_modulo(&monkeys),
+        monkeys,
+        round: 0,
+        nb_rounds: if part == Part::One { 20 } else { 10_000 },
+        divide_by_3: part == Part::One,
+    }
+}
+
+impl Simulation for Rounds {
+    fn step(&mut self) -> bool {
+        if self.round == self.nb_rounds {
+            return false;
+        }
+        let monkeys = std::mem::take(&mut self.monkeys);
+        self.monkeys = handle_round(monkeys, self.divide_by_3, self.keep_cool_modulo);
+        self.round += 1;
+        true
+    }
+
+    /// Like the statement of the puzzle: `Monkey 0: 20, 23, 27, 26`.
+    fn render(&self) -> String {
+        self.monkeys
+            .iter()
+            .enumerate()
+            .map(|(idx, monkey)| {
+                let items: Vec<String> = monkey.items.iter().map(usize::to_string).collect();
+                format!(
+                    "Monkey {idx}: {:<40} inspected {} items",
+                    items.join(", "),
+                    monkey.nb_inspected_items
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "round {} of {}, monkey business {}",
+            self.round,
+            self.nb_rounds,
+            monkey_business(&self.monkeys)
+        )
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+fn handle_turn_of_monkey_x(
+    monkeys: Vec<Monkey>,
+    x: usize,
+    divide_by_3: bool,
+    stay_cool: usize,
+) -> Vec<Monkey> {
+    let mut new_monkeys = monkeys;
+    let mut monkey = new_monkeys[x].clone();
+    // dividing by 3 keeps the tests right as long as the worry levels stay in Z/3nZ
+    let modulo = if divide_by_3 {
+        3 * stay_cool
+    } else {
+        stay_cool
+    };
+    for item in &monkey.items {
+        let mut worry_level = monkey
+            .operation
+            .apply(*item)
+            .or_else(|| monkey.operation.apply(*item % modulo))
+            // parse made sure that the operands and modulo are small enough
+            .unwrap_or_default();
+        if divide_by_3 {
+            worry_level /= 3;
+        } else {
+            worry_level %= stay_cool;
+        }
+        let target_monkey_idx = if worry_level.is_multiple_of(monkey.test.divisible_by) {
+            monkey.test.is_true
+        } else {
+            monkey.test.is_false
+        };
+        new_monkeys[target_monkey_idx].items.push(worry_level);
+        monkey.nb_inspected_items += 1;
+    }
+    monkey.items.clear();
+    new_monkeys[x] = monkey;
+    new_monkeys
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Operation {
+    Times(usize),
+    Add(usize),
+    Square,
+}
+
+impl Operation {
+    /// The new worry level, unless it overflows.
+    fn apply(self, old: usize) -> Option<usize> {
+        match self {
+            Operation::Times(o) => old.checked_mul(o),
+            Operation::Add(o) => old.checked_add(o),
+            Operation::Square => old.checked_mul(old),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Test {
+    divisible_by: usize,
+    is_true: usize,
+    is_false: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<usize>,
+    operation: Operation,
+    test: Test,
+    nb_inspected_items: usize,
+}
+
+impl PartialEq for Monkey {
+    fn eq(&self, other: &Self) -> bool {
+        self.items.eq(&other.items)
+            && self.operation == other.operation
+            && self.test == other.test
+            && self.nb_inspected_items == other.nb_inspected_items
+    }
+}
+
+/// A line describing a monkey, with the value that follows its label.
+type Field<'a> = (usize, &'a str, &'a str);
+
+/// Labels of the lines describing a monkey, in order.
+const LABELS: [&str; 6] = [
+    "Monkey ",
+    "Starting items: ",
+    "Operation: new = old ",
+    "Test: divisible by ",
+    "If true: throw to monkey ",
+    "If false: throw to monkey ",
+];
+
+/// Parses the notes about the monkeys, and gives the fields telling where they throw items:
+/// they can only be checked once every monkey is known.
+fn parse_monkeys(data: &str) -> Result<(Vec<Monkey>, Vec<Field<'_>>), ParseError> {
+    let mut targets = vec![];
+    let mut position = 0;
+    let mut modulo: usize = 1;
+    let monkeys = parse::groups(data, |monkey_lines| {
+        let [header, items, op, divisor, if_true, if_false] = fields(monkey_lines)?;
+        let error = |idx| move |error| ParseError::new(Day11::DAY, idx, error);
+
+        let (idx, line, header) = header;
+        if header != format!("{position}:") {
+            let expected = format!("`{position}:`, the position of the monkey");
+            return Err(error(idx)(Expected::at(column_of(line, header), expected)));
+        }
+        position += 1;
+
+        let (idx, line, items) = items;
+        let items = parse::separated(items, ", ", |worry_level| {
+            number::<usize>(worry_level, worry_level)
+        })
+        .map_err(|e| error(idx)(e.within(line, items)))?;
+
+        let (idx, line, op) = op;
+        let operand = |text| match number::<usize>(line, text) {
+            Ok(n) if n > MAX_MODULO => Err(Expected::at(
+                column_of(line, text),
+                format!("an operand up to {MAX_MODULO}"),
+            )),
+            result => result,
+        };
+        let operation = if op == "* old" {
+            Ok(Operation::Square)
+        } else if let Some(n) = op.strip_prefix("* ") {
+            operand(n).map(Operation::Times)
+        } else if let Some(n) = op.strip_prefix("+ ") {
+            operand(n).map(Operation::Add)
+        } else {
+            Err(Expected::at(
+                column_of(line, op),
+                "an operation (`* old`, `* <number>` or `+ <number>`)",
+            ))
+        }
+        .map_err(error(idx))?;
+
+        let (idx, line, divisor) = divisor;
+        let test = match number::<usize>(line, divisor) {
+            Ok(0) => Err(Expected::at(
+                column_of(line, divisor),
+                "a divisor other than 0",
+            )),
+            Ok(n) if modulo.checked_mul(n).is_none_or(|m| m > MAX_MODULO) => Err(Expected::at(
+                column_of(line, divisor),
+                format!("a divisor keeping the product of the divisors up to {MAX_MODULO}"),
+            )),
+            result => result,
+        }
+        .map_err(error(idx))?;
+        modulo *= test;
+
+        let [is_true, is_false] = [if_true, if_false].map(|(idx, line, target)| {
+            targets.push((idx, line, target));
+            number::<usize>(line, target).map_err(error(idx))
+        });
+
+        Ok(Monkey {
+            items,
+            operation,
+            test: Test {
+                divisible_by: test,
+                is_true: is_true?,
+                is_false: is_false?,
+            },
+            nb_inspected_items: 0,
+        })
+    })?;
+
+    Ok((monkeys, targets))
+}
+
+/// The text that follows the label of each line describing a monkey.
+fn fields<'a>(monkey_lines: &[Line<'a>]) -> Result<[Field<'a>; 6], ParseError> {
+    if let Some(&(idx, _)) = monkey_lines.get(LABELS.len()) {
+        let error = Expected::at(1, "a blank line before the next monkey");
+        return Err(ParseError::new(Day11::DAY, idx, error));
+    }
+    let (last_idx, _) = monkey_lines[monkey_lines.len() - 1];
+    let mut fields = [(0, "", ""); 6];
+    for (position, label) in LABELS.into_iter().enumerate() {
+        let Some(&(idx, line)) = monkey_lines.get(position) else {
+            let error = Expected::at(1, format!("`{}`", label.trim_end()));
+            return Err(ParseError::new(Day11::DAY, last_idx + 1, error));
+        };
+        let text = line.trim_start();
+        let Some(value) = text.strip_prefix(label) else {
+            let error = Expected::at(column_of(line, text), format!("`{}`", label.trim_end()));
+            return Err(ParseError::new(Day11::DAY, idx, error));
+        };
+        fields[position] = (idx, line, value);
+    }
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_monkeys, Day11, Monkey, Operation, Test};
+    use crate::Solution;
+
+    #[test]
+    fn parse_one_monkey() {
+        let input = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+";
+        let (monkeys, _) = parse_monkeys(input).unwrap();
+        assert!(monkeys.contains(&Monkey {
+            items: vec![79, 98],
+            operation: Operation::Times(19),
+            test: Test {
+                divisible_by: 23,
+                is_true: 2,
+                is_false: 3
+            },
+            nb_inspected_items: 0
+        }));
+    }
+
+    #[test]
+    fn parse_monkeys_without_blank_line() {
+        let monkey = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+        let error = parse_monkeys(&format!("{monkey}{monkey}")).unwrap_err();
+        assert_eq!((error.line, error.column), (7, 1));
+        assert_eq!(error.expected, "a blank line before the next monkey");
+
+        let error = parse_monkeys(&monkey.replace("98", "9 8")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 23));
+    }
+
+    #[test]
+    fn parse_monkey_with_missing_line() {
+        let input = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+
+  Test: divisible by 23
+    If true: throw to monkey 0
+";
+        let error = parse_monkeys(input).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.expected, "`Test: divisible by`");
+    }
+
+    #[test]
+    fn parse_monkeys_out_of_order_or_too_big() {
+        let monkey = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+        let error = parse_monkeys(&monkey.replace("0:", "1:")).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 8));
+        assert_eq!(error.expected, "`0:`, the position of the monkey");
+
+        let error = parse_monkeys(&monkey.replace("19", "1073741825")).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 26));
+
+        let two = format!("{monkey}\n{}", monkey.replace("0:", "1:"));
+        let error = parse_monkeys(&two.replace("23", "65536")).unwrap_err();
+        assert_eq!((error.line, error.column), (11, 22));
+        assert_eq!(
+            error.expected,
+            "a divisor keeping the product of the divisors up to 1073741824"
+        );
+
+        let error = Day11.parse("\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+
+    #[test]
+    fn worry_levels_that_overflow() {
+        let input = "\
+Monkey 0:
+  Starting items: 18446744073709551615
+  Operation: new = old * old
+  Test: divisible by 7
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 1
+  Operation: new = old + 1073741824
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+        let monkeys = Day11.parse(input).unwrap();
+        assert_eq!(39 * 40, Day11.part1(&monkeys));
+        assert_eq!(19_999 * 20_000, Day11.part2(&monkeys));
+    }
+
+    #[test]
+    fn part1_sample() {
+        let input = Day11
+            .parse(include_str!("../inputs/day11.sample.txt"))
+            .unwrap();
+
+        assert_eq!(10_605, Day11.part1(&input));
+    }
+
+    #[test]
+    fn part2_sample() {
+        let input = Day11
+            .parse(include_str!("../inputs/day11.sample.txt"))
+            .unwrap();
+
+        assert_eq!(2_713_310_158, Day11.part2(&input));
+    }
+}
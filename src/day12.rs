@@ -0,0 +1,98 @@
+use pathfinding::prelude::dijkstra;
+
+use crate::grid::{Grid, Pos};
+use crate::parse::{Expected, ParseError};
+use crate::Solution;
+
+pub struct Day12;
+
+#[derive(Debug)]
+pub struct Heightmap {
+    grid: Grid<usize>,
+    start: Pos,
+    goal: Pos,
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Heightmap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        let mut start = None;
+        let mut goal = None;
+        let grid = Grid::parse(Self::DAY, data, |pos, ch| match ch {
+            'S' => {
+                start = Some(pos);
+                Ok(0)
+            }
+            'E' => {
+                goal = Some(pos);
+                Ok('z' as usize - 'a' as usize)
+            }
+            'a'..='z' => Ok(ch as usize - 'a' as usize),
+            _ => Err("an elevation (a-z), S or E"),
+        })?;
+        let missing =
+            |expected: &str| ParseError::new(Self::DAY, grid.height(), Expected::at(1, expected));
+        let heightmap = Heightmap {
+            start: start.ok_or_else(|| missing("the start position S"))?,
+            goal: goal.ok_or_else(|| missing("the best signal position E"))?,
+            grid,
+        };
+        let Heightmap { grid, start, goal } = &heightmap;
+        if fewest_steps(grid, *start, *goal).is_none() {
+            let expected = "a path from S to E, at most one higher at each step";
+            let error = Expected::at(goal.x + 1, expected);
+            return Err(ParseError::new(Self::DAY, goal.y, error));
+        }
+        Ok(heightmap)
+    }
+
+    fn part1(&self, heightmap: &Self::Input) -> usize {
+        let Heightmap { grid, start, goal } = heightmap;
+        // parse made sure that there is a path
+        fewest_steps(grid, *start, *goal).unwrap_or_default()
+    }
+
+    fn part2(&self, heightmap: &Self::Input) -> usize {
+        let Heightmap { grid, goal, .. } = heightmap;
+        // for part 2, we will try each start node that is at elevation "a" and take the min from them
+        grid.iter()
+            .filter(|(_, v)| **v == 0)
+            .map(|(pos, _)| pos)
+            // some clusters of "a" can't join the "z" so just ignore them if we start in one of these clusters
+            .filter_map(|start| fewest_steps(grid, start, *goal))
+            .min()
+            // S is at elevation "a" too, and parse made sure that it joins E
+            .unwrap_or_default()
+    }
+}
+
+/// The number of steps of the shortest path from `start` to `goal`, if there is one.
+fn fewest_steps(grid: &Grid<usize>, start: Pos, goal: Pos) -> Option<usize> {
+    let (_, steps) = dijkstra(&start, |&p| successors(grid, p), |p| *p == goal)?;
+    Some(steps)
+}
+
+/// The neighbours that are at most one higher, all one step away.
+fn successors(grid: &Grid<usize>, pos: Pos) -> Vec<(Pos, usize)> {
+    let our_value = grid[pos];
+    grid.neighbours4(pos)
+        .filter(|&next| grid[next] <= our_value + 1)
+        .map(|next| (next, 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day12;
+    use crate::Solution;
+
+    #[test]
+    fn goal_out_of_reach() {
+        let error = Day12.parse("Sab\nzzE").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
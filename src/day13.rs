@@ -0,0 +1,291 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use crate::parse::{Expected, ParseError};
+use crate::Solution;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Packet>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        let mut packets = vec![];
+        let mut nb_lines = 0;
+        for (idx, line) in data.lines().enumerate() {
+            nb_lines = idx + 1;
+            if !line.is_empty() {
+                let packet =
+                    Packet::parse(line).map_err(|error| ParseError::new(Self::DAY, idx, error))?;
+                packets.push(packet);
+            }
+        }
+        if packets.len() % 2 != 0 {
+            let error = Expected::at(1, "the right packet of the last pair");
+            return Err(ParseError::new(Self::DAY, nb_lines, error));
+        }
+        Ok(packets)
+    }
+
+    fn part1(&self, packets: &Self::Input) -> usize {
+        packets
+            .chunks(2)
+            .map(|chunk| {
+                let [left, right] = <&[Packet; 2]>::try_from(chunk).ok().unwrap();
+                (left, right)
+            })
+            .enumerate()
+            .filter_map(|(idx, (left, right))| if left < right { Some(idx + 1) } else { None })
+            .sum::<usize>()
+    }
+
+    fn part2(&self, packets: &Self::Input) -> usize {
+        let mut packets = packets.clone();
+
+        let delimiter1 = Packet::parse("[[2]]").unwrap();
+        let delimiter2 = Packet::parse("[[6]]").unwrap();
+        packets.push(delimiter1.clone());
+        packets.push(delimiter2.clone());
+        packets.sort();
+
+        packets
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, packet)| {
+                if packet == &delimiter1 || packet == &delimiter2 {
+                    Some(idx + 1)
+                } else {
+                    None
+                }
+            })
+            .product::<usize>()
+    }
+}
+
+/// Lists are parsed, compared and displayed recursively: deeper ones would overflow the stack.
+const MAX_DEPTH: usize = 100;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Packet {
+    Number(u8),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.cmp(b),
+            // I am lucky here to see that vec comparison do the expected behaviour :')
+            (Self::List(v1), Self::List(v2)) => v1.cmp(v2),
+            (Self::Number(a), Self::List(v1)) => vec![Self::Number(*a)].cmp(v1),
+            (Self::List(v1), Self::Number(a)) => v1.cmp(&vec![Self::Number(*a)]),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Packet {
+    pub fn parse(s: &str) -> Result<Self, Expected> {
+        let chars = s.chars().collect::<Vec<char>>();
+        if chars.first() != Some(&'[') {
+            return Err(Expected::at(1, "`[`"));
+        }
+        let (packet, remaining) = Packet::parse_list(&chars, chars.len(), 1)?;
+        if !remaining.is_empty() {
+            return Err(Expected::at(
+                column(chars.len(), remaining),
+                "the end of the packet",
+            ));
+        }
+        Ok(packet)
+    }
+
+    /// Parses the list that starts `chars`, nested in `depth - 1` others; `total` is the
+    /// length of the whole packet, to locate errors.
+    fn parse_list(
+        chars: &[char],
+        total: usize,
+        depth: usize,
+    ) -> Result<(Packet, &[char]), Expected> {
+        if depth > MAX_DEPTH {
+            let expected = format!("at most {MAX_DEPTH} nested lists");
+            return Err(Expected::at(column(total, chars), expected));
+        }
+        let mut chars_to_parse = &chars[1..];
+        let mut packets = vec![];
+        if chars_to_parse.first() == Some(&']') {
+            return Ok((Packet::List(packets), &chars_to_parse[1..]));
+        }
+        loop {
+            let (packet, remaining) = match chars_to_parse.first() {
+                Some('[') => Packet::parse_list(chars_to_parse, total, depth + 1)?,
+                // numbers
+                _ => Packet::parse_num(chars_to_parse, total)?,
+            };
+            packets.push(packet);
+            match remaining.first() {
+                Some(',') => chars_to_parse = &remaining[1..],
+                Some(']') => return Ok((Packet::List(packets), &remaining[1..])),
+                _ => return Err(Expected::at(column(total, remaining), "`,` or `]`")),
+            }
+        }
+    }
+
+    fn parse_num(chars: &[char], total: usize) -> Result<(Packet, &[char]), Expected> {
+        let mut num: Option<u8> = None;
+        let mut chars_to_parse = chars;
+        while let Some(digit) = chars_to_parse.first().and_then(|c| c.to_digit(10)) {
+            num = match num.unwrap_or(0).checked_mul(10) {
+                Some(n) => n.checked_add(digit as u8),
+                None => None,
+            };
+            if num.is_none() {
+                return Err(Expected::at(column(total, chars), "a number up to 255"));
+            }
+            chars_to_parse = &chars_to_parse[1..];
+        }
+
+        match num {
+            Some(n) => Ok((Packet::Number(n), chars_to_parse)),
+            None => Err(Expected::at(column(total, chars), "a number or a list")),
+        }
+    }
+}
+
+/// The notation of the input, without spaces: `[1,[2,3]]`.
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::Number(n) => write!(f, "{n}"),
+            Packet::List(packets) => {
+                write!(f, "[")?;
+                for (idx, packet) in packets.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{packet}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// Column of the first of the `remaining` chars of a packet of `total` chars.
+fn column(total: usize, remaining: &[char]) -> usize {
+    total - remaining.len() + 1
+}
+
+#[cfg(test)]
+mod test {
+    use super::Packet;
+    use crate::parse::Expected;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    /// Lists nested up to 4 levels deep.
+    fn packet() -> impl Strategy<Value = Packet> {
+        let leaf = any::<u8>().prop_map(Packet::Number);
+        let item = leaf.prop_recursive(4, 64, 8, |inner| vec(inner, 0..8).prop_map(Packet::List));
+        vec(item, 0..8).prop_map(Packet::List)
+    }
+
+    proptest! {
+        #[test]
+        fn display_is_parsed_back(packet in packet()) {
+            prop_assert_eq!(Packet::parse(&packet.to_string()), Ok(packet));
+        }
+    }
+
+    #[test]
+    fn parse_should_be_fine() {
+        let elt = Packet::parse("[1,[2,[3]],4]").unwrap();
+        assert_eq!(
+            elt,
+            Packet::List(vec![
+                Packet::Number(1),
+                Packet::List(vec![
+                    Packet::Number(2),
+                    Packet::List(vec![Packet::Number(3)])
+                ]),
+                Packet::Number(4)
+            ])
+        )
+    }
+
+    #[test]
+    fn display_like_the_input() {
+        let line = "[1,[2,[3,[4,[5,6,7]]]],8,9]";
+        assert_eq!(Packet::parse(line).unwrap().to_string(), line);
+        assert_eq!(Packet::parse("[[],[[]]]").unwrap().to_string(), "[[],[[]]]");
+    }
+
+    #[test]
+    fn parse_errors_are_located() {
+        assert_eq!(Packet::parse("1").err(), Some(Expected::at(1, "`[`")));
+        assert_eq!(
+            Packet::parse("[1,,2]").err(),
+            Some(Expected::at(4, "a number or a list"))
+        );
+        assert_eq!(
+            Packet::parse("[1,[2]").err(),
+            Some(Expected::at(7, "`,` or `]`"))
+        );
+        assert_eq!(
+            Packet::parse("[256]").err(),
+            Some(Expected::at(2, "a number up to 255"))
+        );
+        assert_eq!(
+            Packet::parse("[1]]").err(),
+            Some(Expected::at(4, "the end of the packet"))
+        );
+        let deep = format!("{}{}", "[".repeat(101), "]".repeat(101));
+        assert_eq!(
+            Packet::parse(&deep).err(),
+            Some(Expected::at(101, "at most 100 nested lists"))
+        );
+    }
+
+    #[test]
+    fn vec_comparison_shorter_but_greater() {
+        let v1 = vec![1, 2, 3, 4];
+        let v2 = vec![1, 3];
+        assert!(v1 < v2);
+    }
+
+    #[test]
+    fn vec_comparison_empty() {
+        let v1 = vec![];
+        let v2 = vec![1, 2, 3];
+        assert!(v1 < v2);
+    }
+
+    #[test]
+    fn vec_comparison_shorter() {
+        let v1 = vec![1, 2];
+        let v2 = vec![1, 2, 3];
+        assert!(v1 < v2);
+    }
+
+    #[test]
+    fn vec_comparison_middle() {
+        let v1 = vec![1, 1, 1];
+        let v2 = vec![1, 2, 1];
+        assert!(v1 < v2);
+    }
+
+    #[test]
+    fn vec_comparison_end() {
+        let v1 = vec![1, 1, 1];
+        let v2 = vec![1, 1, 2];
+        assert!(v1 < v2);
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt::{Display, Formatter};
+
+use tracing::trace;
+
+use crate::geometry::{Bounds, Point, Vector};
+use crate::grid::{Grid, Pos};
+use crate::parse::{self, Expected, ParseError};
+use crate::runner::Part;
+use crate::simulation::Simulation;
+use crate::Solution;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Game;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(data)
+    }
+
+    fn part1(&self, game: &Self::Input) -> usize {
+        let mut game = game.clone();
+        let mut counter = 0;
+        while game.drop_sand() {
+            counter += 1;
+        }
+        trace!("cave when the sand flows into the abyss:\n{game}");
+        counter
+    }
+
+    fn part2(&self, game: &Self::Input) -> usize {
+        let mut game = game.clone();
+        game.add_floor();
+        let mut counter = 0;
+        while game.drop_sand() {
+            counter += 1;
+        }
+        trace!("cave when the source is blocked:\n{game}");
+        counter
+    }
+}
+
+const SOURCE: Point = Point::new(500, 0);
+
+/// The whole cave is kept in memory: farther rocks would take gigabytes.
+const MAX_COORDINATE: usize = 1000;
+
+/// Where a unit of sand tries to go, in order.
+const FALLS: [Vector; 3] = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)];
+
+/// The cave, from the source down to the floor two rows below the lowest rock, and wide enough
+/// for the pile of sand that reaches the source.
+#[derive(Debug, Clone)]
+pub struct Game {
+    grid: Grid<Content>,
+    /// point of the top left cell of the grid
+    origin: Point,
+}
+
+impl Game {
+    fn new(rocks: &[Point]) -> Self {
+        let mut bounds = Bounds::of(rocks.iter().copied().chain([SOURCE])).unwrap();
+        let floor = bounds.max.y + 2;
+        // the pile of sand is a triangle from the source to the floor
+        bounds.extend(SOURCE + Point::new(floor + 1, floor));
+        bounds.extend(SOURCE + Point::new(-floor - 1, floor));
+        let size = (bounds.max - bounds.min).unsigned().unwrap() + Point::new(1, 1);
+        let mut game = Game {
+            grid: Grid::new(size.x, size.y, Content::Air),
+            origin: bounds.min,
+        };
+        for &rock in rocks {
+            game.set(rock, Content::Rock);
+        }
+        game.set(SOURCE, Content::Source);
+        game
+    }
+
+    fn pos(&self, point: Point) -> Option<Pos> {
+        (point - self.origin)
+            .unsigned()
+            .filter(|&pos| self.grid.contains(pos))
+    }
+
+    fn content(&self, point: Point) -> Option<Content> {
+        self.pos(point).map(|pos| self.grid[pos])
+    }
+
+    fn set(&mut self, point: Point, content: Content) {
+        let pos = self.pos(point).expect("point out of the cave");
+        self.grid[pos] = content;
+    }
+
+    /// The floor of part 2, on the last row.
+    fn add_floor(&mut self) {
+        let y = self.grid.height() - 1;
+        for x in 0..self.grid.width() {
+            self.grid[Point::new(x, y)] = Content::Rock;
+        }
+    }
+
+    /// Drops a unit of sand from the source, and tells whether it came to rest: it doesn't
+    /// when it falls below the lowest rock, or when the source is already blocked.
+    fn drop_sand(&mut self) -> bool {
+        if self.content(SOURCE) == Some(Content::Sand) {
+            return false;
+        }
+        let mut sand = SOURCE;
+        while let Some(next) = FALLS
+            .map(|fall| sand + fall)
+            .into_iter()
+            .find(|&point| self.content(point) == Some(Content::Air))
+        {
+            if self.pos(next).unwrap().y == self.grid.height() - 1 {
+                // nothing below to stop it
+                return false;
+            }
+            sand = next;
+        }
+        self.set(sand, Content::Sand);
+        true
+    }
+}
+
+/// The cave after each unit of sand.
+#[derive(Debug, Clone)]
+pub struct Flow {
+    game: Game,
+    /// units of sand at rest
+    units: usize,
+}
+
+pub fn flow(mut game: Game, part: Part) -> Flow {
+    if part == Part::Two {
+        game.add_floor();
+    }
+    Flow { game, units: 0 }
+}
+
+impl Simulation for Flow {
+    fn step(&mut self) -> bool {
+        let rests = self.game.drop_sand();
+        self.units += usize::from(rests);
+        rests
+    }
+
+    fn render(&self) -> String {
+        self.game.to_string()
+    }
+
+    fn status(&self) -> String {
+        format!("{} units of sand at rest", self.units)
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+/// Draws the cave with its rocks and sand.
+impl Display for Game {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+enum Content {
+    Air,
+    Sand,
+    Rock,
+    Source,
+}
+
+impl Display for Content {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ch = match self {
+            Content::Air => '.',
+            Content::Sand => 'o',
+            Content::Rock => '#',
+            Content::Source => '+',
+        };
+        write!(f, "{ch}")
+    }
+}
+
+fn parse_input(input: &str) -> Result<Game, ParseError> {
+    let mut rocks = vec![];
+    for (idx, line) in input.lines().enumerate() {
+        let error = |error| ParseError::new(Day14::DAY, idx, error);
+        let parts = parse::separated(line, " -> ", |part| {
+            // the cave is below the source: no negative coordinates
+            match part.parse::<Point<usize>>()? {
+                point if point.x.max(point.y) <= MAX_COORDINATE => Ok(point.signed()),
+                _ => {
+                    let expected = format!("a point with coordinates up to {MAX_COORDINATE}");
+                    Err(Expected::at(1, expected))
+                }
+            }
+        })
+        .map_err(error)?;
+        if parts.len() < 2 {
+            return Err(error(Expected::at(
+                line.len() + 1,
+                "` -> ` and another point",
+            )));
+        }
+        for (i, pair) in parts.windows(2).enumerate() {
+            let [start, end] = [pair[0], pair[1]];
+            let step = (end - start).signum();
+            if step.x != 0 && step.y != 0 {
+                let second = line.split(" -> ").nth(i + 1).unwrap();
+                let expected = "a point on the same row or column as the previous one";
+                return Err(error(Expected::at(1, expected).within(line, second)));
+            }
+            let mut rock = start;
+            rocks.push(rock);
+            while rock != end {
+                rock += step;
+                rocks.push(rock);
+            }
+        }
+    }
+    if rocks.is_empty() {
+        let error = Expected::at(1, "a path of rock");
+        return Err(ParseError::new(Day14::DAY, 0, error));
+    }
+    Ok(Game::new(&rocks))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_input, Content, Point, SOURCE};
+    use crate::parse::ParseError;
+
+    #[test]
+    fn rocks_too_far() {
+        assert_eq!(
+            parse_input("498,4 -> 498,6\n4000000000,5 -> 4000000000,6").err(),
+            Some(ParseError {
+                day: 14,
+                line: 2,
+                column: 1,
+                expected: String::from("a point with coordinates up to 1000")
+            })
+        );
+    }
+
+    #[test]
+    fn loading_data() {
+        let input = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9\
+";
+        let game = parse_input(input).unwrap();
+        assert_eq!(game.content(Point::new(498, 4)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(498, 5)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(498, 6)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(497, 6)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(496, 6)), Some(Content::Rock));
+
+        assert_eq!(game.content(Point::new(503, 4)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(502, 4)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(502, 5)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(502, 6)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(502, 7)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(502, 8)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(502, 9)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(501, 9)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(500, 9)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(499, 9)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(498, 9)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(497, 9)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(496, 9)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(495, 9)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(494, 9)), Some(Content::Rock));
+        assert_eq!(game.content(Point::new(499, 8)), Some(Content::Air));
+        assert_eq!(game.content(SOURCE), Some(Content::Source));
+    }
+}
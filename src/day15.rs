@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use crate::geometry::Point;
+use crate::parse::{self, Expected, ParseError, Scanner};
+use crate::Solution;
+
+pub struct Day15 {
+    /// the row where part 1 counts the positions that can't contain a beacon
+    pub row: isize,
+    /// part 2 looks for the distress beacon with both coordinates in `0..=search_limit`
+    pub search_limit: isize,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self {
+            row: 2_000_000,
+            search_limit: 4_000_000,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<Report>;
+    type Part1 = usize;
+    type Part2 = isize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        let reports = parse::lines(Self::DAY, data, |line| {
+            let mut scanner = Scanner::new(line);
+            let mut point = |x_tag, y_tag| -> Result<Point, Expected> {
+                scanner.tag(x_tag)?;
+                let x = coordinate(&mut scanner)?;
+                scanner.tag(y_tag)?;
+                Ok(Point::new(x, coordinate(&mut scanner)?))
+            };
+            let sensor = point("Sensor at x=", ", y=")?;
+            let beacon = point(": closest beacon is at x=", ", y=")?;
+            scanner.end()?;
+            Ok(Report { sensor, beacon })
+        })?;
+        if self.distress_beacon(&reports).is_none() {
+            let expected = format!(
+                "a position within 0..={} out of reach of every sensor, for the distress beacon",
+                self.search_limit
+            );
+            return Err(ParseError::new(
+                Self::DAY,
+                reports.len(),
+                Expected::at(1, expected),
+            ));
+        }
+        Ok(reports)
+    }
+
+    fn part1(&self, reports: &Self::Input) -> usize {
+        let y_that_matters = self.row;
+        Game::scan_row(reports, y_that_matters)
+            .map
+            .iter()
+            .filter(|(&p, &content)| p.y == y_that_matters && content == Content::NotABeaconForSure)
+            .count()
+    }
+
+    fn part2(&self, reports: &Self::Input) -> isize {
+        // parse made sure that there is one
+        self.distress_beacon(reports)
+            .map_or(0, |Point { x, y }| x * 4_000_000 + y)
+    }
+}
+
+/// Largest coordinate accepted, far beyond the puzzle ones, so that the distances and the
+/// tuning frequency can't overflow.
+const MAX_COORDINATE: isize = 1_000_000_000;
+
+/// Reads a coordinate of a position, within `MAX_COORDINATE` of 0.
+fn coordinate(scanner: &mut Scanner) -> Result<isize, Expected> {
+    let column = scanner.column();
+    match scanner.number()? {
+        value if (-MAX_COORDINATE..=MAX_COORDINATE).contains(&value) => Ok(value),
+        _ => Err(Expected::at(
+            column,
+            format!("a coordinate between -{MAX_COORDINATE} and {MAX_COORDINATE}"),
+        )),
+    }
+}
+
+impl Day15 {
+    /// The position within the search limits that no sensor reaches, if there is one.
+    fn distress_beacon(&self, reports: &[Report]) -> Option<Point> {
+        // the only free spot is surrounded by covered ones, so it lies just outside the range
+        // of several sensors: on the diagonals x+y=a and x-y=b bordering those ranges
+        let mut ascending = vec![];
+        let mut descending = vec![];
+        for report in reports {
+            let Point { x, y } = report.sensor;
+            let range = report.range();
+            ascending.push(x + y - range - 1);
+            ascending.push(x + y + range + 1);
+            descending.push(x - y - range - 1);
+            descending.push(x - y + range + 1);
+        }
+
+        let limits = 0..=self.search_limit;
+        ascending
+            .iter()
+            .flat_map(|a| descending.iter().map(move |b| (a, b)))
+            .filter(|(a, b)| (*a - *b) % 2 == 0)
+            .map(|(a, b)| Point::new((a + b) / 2, (a - b) / 2))
+            .filter(|p| limits.contains(&p.x) && limits.contains(&p.y))
+            .find(|p| {
+                reports
+                    .iter()
+                    .all(|report| report.sensor.manhattan(*p) > report.range())
+            })
+    }
+}
+
+/// A sensor and the closest beacon it detected.
+#[derive(Debug, Copy, Clone)]
+pub struct Report {
+    sensor: Point,
+    beacon: Point,
+}
+
+impl Report {
+    /// every position closer to the sensor than its beacon can't be a beacon
+    fn range(&self) -> isize {
+        self.sensor.manhattan(self.beacon)
+    }
+}
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+enum Content {
+    Sensor,
+    Beacon,
+    NotABeaconForSure,
+}
+
+#[derive(Debug, Clone)]
+struct Game {
+    map: HashMap<Point, Content>,
+}
+
+impl Game {
+    fn scan_row(reports: &[Report], limit: isize) -> Self {
+        let mut map = HashMap::new();
+        for report in reports {
+            let Report { sensor, beacon } = *report;
+            let Point {
+                x: sensor_x,
+                y: sensor_y,
+            } = sensor;
+
+            map.insert(sensor, Content::Sensor);
+            map.insert(beacon, Content::Beacon);
+
+            let distance_covered_by_sensor = report.range();
+            if sensor_y - distance_covered_by_sensor <= limit
+                && limit <= sensor_y + distance_covered_by_sensor
+            {
+                let dist_that_matter = (limit - sensor_y).abs();
+                let remaining = distance_covered_by_sensor - dist_that_matter;
+                let x_range = sensor_x - remaining..=sensor_x + remaining;
+                for x in x_range {
+                    let p = Point::new(x, limit);
+                    map.entry(p).or_insert(Content::NotABeaconForSure);
+                }
+            }
+        }
+
+        Game { map }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day15;
+    use crate::Solution;
+
+    #[test]
+    fn parse_errors_point_at_the_faulty_part() {
+        let error = |line: &str| {
+            let error = Day15::default().parse(line).unwrap_err();
+            (error.column, error.expected)
+        };
+        assert_eq!(
+            error("Sensor at x=2, y=18: closest beacon is at x=-2, y=x15"),
+            (51, String::from("a number"))
+        );
+        assert_eq!(
+            error("Sensor at x=2, y=18; closest beacon is at x=-2, y=15"),
+            (20, String::from("`: closest beacon is at x=`"))
+        );
+        assert_eq!(
+            error("Sensor at x=2, y=18: closest beacon is at x=-2, y=15."),
+            (53, String::from("the end of the line"))
+        );
+        assert_eq!(
+            error("Sensor at x=2, y=18: closest beacon is at x=-2000000000, y=15"),
+            (
+                45,
+                String::from("a coordinate between -1000000000 and 1000000000")
+            )
+        );
+    }
+
+    #[test]
+    fn no_room_for_the_distress_beacon() {
+        let day = Day15 {
+            row: 0,
+            search_limit: 2,
+        };
+        let error = day
+            .parse("Sensor at x=1, y=1: closest beacon is at x=3, y=1")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert!(day.parse("").is_err());
+    }
+
+    #[test]
+    fn part1_sample() {
+        let day = Day15 {
+            row: 10,
+            search_limit: 20,
+        };
+        let input = day
+            .parse(include_str!("../inputs/day15.sample.txt"))
+            .unwrap();
+        assert_eq!(day.part1(&input), 26);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let day = Day15 {
+            row: 10,
+            search_limit: 20,
+        };
+        let input = day
+            .parse(include_str!("../inputs/day15.sample.txt"))
+            .unwrap();
+        assert_eq!(day.part2(&input), 56_000_011);
+    }
+}
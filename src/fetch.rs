@@ -0,0 +1,378 @@
+//! Downloads of the puzzle inputs, kept in the inputs directory so that each one is only
+//! downloaded once.
+
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use tracing::{info, warn};
+
+use crate::input;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2022";
+
+/// The site asks the tools downloading inputs to say who they are.
+const USER_AGENT: &str = concat!(
+    "aoc-2022-rust/",
+    env!("CARGO_PKG_VERSION"),
+    " (puzzle input fetcher)"
+);
+
+/// An HTTP client presenting itself to the site.
+pub(crate) fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .build()
+}
+
+/// Downloads puzzle inputs into a directory, unless they are already there.
+pub struct Fetcher {
+    base_url: String,
+    /// value of the `session` cookie of the site, only needed for the inputs not cached yet
+    session: Option<String>,
+    inputs_dir: PathBuf,
+    agent: ureq::Agent,
+    /// how many times a throttled download is tried again
+    retries: u32,
+    /// longest wait before trying again, whatever the server asks for
+    max_wait: Duration,
+}
+
+/// Where a puzzle input was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    /// the server asks to log in: the session token is wrong or expired
+    LoginRequired {
+        url: String,
+    },
+    /// an HTML page, or nothing, instead of the input
+    NotAnInput {
+        url: String,
+    },
+    /// a page without the expected content, like a reply to an answer that can't be told
+    UnexpectedPage {
+        url: String,
+    },
+    Status {
+        url: String,
+        status: u16,
+        message: String,
+    },
+    /// still too many requests after waiting as long as the server asked
+    Throttled {
+        url: String,
+    },
+    Transport {
+        url: String,
+        message: String,
+    },
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::NoSession => write!(
+                f,
+                "no session token to download the input (give the `session` cookie of the \
+                 site with --session or AOC_SESSION)"
+            ),
+            FetchError::LoginRequired { url } => write!(
+                f,
+                "{url} asks to log in: the session token is wrong or expired"
+            ),
+            FetchError::NotAnInput { url } => {
+                write!(f, "{url} gave a web page instead of a puzzle input")
+            }
+            FetchError::UnexpectedPage { url } => {
+                write!(f, "{url} gave a page that can't be understood")
+            }
+            FetchError::Status {
+                url,
+                status,
+                message,
+            } => {
+                write!(f, "{url} answered with the status {status}")?;
+                if !message.is_empty() {
+                    write!(f, ": {message}")?;
+                }
+                Ok(())
+            }
+            FetchError::Throttled { url } => {
+                write!(f, "{url} still refuses too many requests, try again later")
+            }
+            FetchError::Transport { url, message } => write!(f, "can't reach {url}: {message}"),
+            FetchError::Io { path, source } => {
+                write!(f, "can't write {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Fetcher {
+            base_url: String::from(base_url.trim_end_matches('/')),
+            session,
+            inputs_dir: PathBuf::from(input::DIR),
+            agent: agent(),
+            retries: 3,
+            max_wait: Duration::from_secs(60),
+        }
+    }
+
+    /// Keeps the inputs in `dir` instead of the usual inputs directory.
+    pub fn inputs_dir(mut self, dir: &Path) -> Self {
+        self.inputs_dir = dir.to_path_buf();
+        self
+    }
+
+    /// Tries a throttled download again `retries` times, waiting at most `max_wait` each time.
+    pub fn retries(mut self, retries: u32, max_wait: Duration) -> Self {
+        self.retries = retries;
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// The input of `day`, downloaded unless it is already in the inputs directory.
+    pub fn fetch(&self, day: u8) -> Result<Fetched, FetchError> {
+        let path = self.inputs_dir.join(input::file_name(day));
+        // a new day starts with an empty input file, waiting for the download
+        if path.metadata().is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+        let data = self.download(day)?;
+        let io_error = |source| FetchError::Io {
+            path: path.clone(),
+            source,
+        };
+        std::fs::create_dir_all(&self.inputs_dir).map_err(io_error)?;
+        // an interrupted write must not look like a cached input
+        let partial = path.with_extension("txt.part");
+        std::fs::write(&partial, data).map_err(io_error)?;
+        std::fs::rename(&partial, &path).map_err(io_error)?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Downloads the input of `day`, waiting and trying again while the server is throttling.
+    pub fn download(&self, day: u8) -> Result<String, FetchError> {
+        let session = self.session.as_deref().ok_or(FetchError::NoSession)?;
+        let url = format!("{}/day/{day}/input", self.base_url);
+        for attempt in 0..=self.retries {
+            info!(url, attempt, "downloading");
+            let response = self
+                .agent
+                .get(&url)
+                .set("Cookie", &format!("session={session}"))
+                .call();
+            let (status, response) = match response {
+                Ok(response) => (response.status(), response),
+                Err(ureq::Error::Status(status, response)) => (status, response),
+                Err(ureq::Error::Transport(error)) => {
+                    return Err(FetchError::Transport {
+                        url,
+                        message: error.to_string(),
+                    })
+                }
+            };
+            if status == 429 || status == 503 {
+                let wait = response
+                    .header("Retry-After")
+                    .and_then(|secs| secs.trim().parse().ok())
+                    .map_or(Duration::from_secs(1 << attempt), Duration::from_secs)
+                    .min(self.max_wait);
+                if attempt < self.retries {
+                    warn!(status, ?wait, "throttled, waiting before trying again");
+                    std::thread::sleep(wait);
+                }
+                continue;
+            }
+            let body = response
+                .into_string()
+                .map_err(|error| FetchError::Transport {
+                    url: url.clone(),
+                    message: error.to_string(),
+                })?;
+            return check(url, status, body);
+        }
+        Err(FetchError::Throttled { url })
+    }
+}
+
+/// Tells the puzzle inputs from the pages the site gives instead.
+fn check(url: String, status: u16, body: String) -> Result<String, FetchError> {
+    let is_html = body.trim_start().starts_with('<');
+    let lowercase = body.to_lowercase();
+    if (status == 400 || is_html) && (lowercase.contains("log in") || lowercase.contains("login")) {
+        return Err(FetchError::LoginRequired { url });
+    }
+    if !(200..300).contains(&status) {
+        let message = if is_html {
+            String::new()
+        } else {
+            let first_line = body.lines().next().unwrap_or("");
+            first_line.trim().chars().take(200).collect()
+        };
+        return Err(FetchError::Status {
+            url,
+            status,
+            message,
+        });
+    }
+    if is_html || body.trim().is_empty() {
+        return Err(FetchError::NotAnInput { url });
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FetchError, Fetched, Fetcher};
+    use crate::stand_in::{temp_dir, Request, StandIn};
+    use std::path::Path;
+    use std::time::Duration;
+
+    fn fetcher(site: &StandIn, dir: &Path) -> Fetcher {
+        Fetcher::new(&site.base_url, Some(String::from("53cr3t")))
+            .inputs_dir(dir)
+            .retries(2, Duration::from_millis(10))
+    }
+
+    #[test]
+    fn download_once_then_use_the_cache() {
+        let site = StandIn::serve(vec![(200, "1000\n2000\n", None)]);
+        let dir = temp_dir("fetch-cache");
+        let path = dir.join("day01.txt");
+        assert_eq!(
+            fetcher(&site, &dir).fetch(1).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(
+            fetcher(&site, &dir).fetch(1).unwrap(),
+            Fetched::Cached(path.clone())
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+        assert_eq!(
+            site.requests(),
+            vec![Request {
+                url: String::from("/2022/day/1/input"),
+                cookie: String::from("session=53cr3t"),
+                body: String::new(),
+            }]
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn cached_inputs_need_no_session() {
+        let dir = temp_dir("fetch-no-session");
+        std::fs::write(dir.join("day02.txt"), "A Y\n").unwrap();
+        let fetcher = Fetcher::new("http://127.0.0.1:9", None).inputs_dir(&dir);
+        assert!(matches!(fetcher.fetch(2), Ok(Fetched::Cached(_))));
+        assert!(matches!(fetcher.fetch(3), Err(FetchError::NoSession)));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn empty_inputs_are_downloaded() {
+        let site = StandIn::serve(vec![(200, "$ ls\n", None)]);
+        let dir = temp_dir("fetch-empty");
+        std::fs::write(dir.join("day07.txt"), "").unwrap();
+        assert!(matches!(
+            fetcher(&site, &dir).fetch(7),
+            Ok(Fetched::Downloaded(_))
+        ));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("day07.txt")).unwrap(),
+            "$ ls\n"
+        );
+        site.requests();
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn login_pages_are_not_inputs() {
+        let site = StandIn::serve(vec![
+            (
+                200,
+                "<!DOCTYPE html><a href=\"/auth/login\">Log In</a>",
+                None,
+            ),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+                None,
+            ),
+            (200, "<html><body>Advent of Code</body></html>", None),
+        ]);
+        let dir = temp_dir("fetch-login");
+        let fetcher = fetcher(&site, &dir);
+        assert!(matches!(
+            fetcher.fetch(5),
+            Err(FetchError::LoginRequired { .. })
+        ));
+        assert!(matches!(
+            fetcher.fetch(5),
+            Err(FetchError::LoginRequired { .. })
+        ));
+        assert!(matches!(
+            fetcher.fetch(5),
+            Err(FetchError::NotAnInput { .. })
+        ));
+        assert!(!dir.join("day05.txt").exists());
+        site.requests();
+    }
+
+    #[test]
+    fn error_pages_give_their_message() {
+        let message = "Please don't repeatedly request this endpoint before it unlocks!";
+        let site = StandIn::serve(vec![(404, message, None)]);
+        let dir = temp_dir("fetch-error");
+        let error = fetcher(&site, &dir).fetch(25).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "{}/day/25/input answered with the status 404: {message}",
+                site.base_url
+            )
+        );
+        site.requests();
+    }
+
+    #[test]
+    fn wait_while_throttled() {
+        let site = StandIn::serve(vec![
+            (429, "slow down", Some("0")),
+            (503, "", None),
+            (200, "noop\n", None),
+        ]);
+        let dir = temp_dir("fetch-throttled");
+        assert!(matches!(
+            fetcher(&site, &dir).fetch(10),
+            Ok(Fetched::Downloaded(_))
+        ));
+        assert_eq!(site.requests().len(), 3);
+        std::fs::remove_dir_all(dir).unwrap();
+
+        let site = StandIn::serve(vec![(429, "", Some("0")); 3]);
+        let dir = temp_dir("fetch-still-throttled");
+        assert!(matches!(
+            fetcher(&site, &dir).fetch(10),
+            Err(FetchError::Throttled { .. })
+        ));
+        site.requests();
+    }
+}
@@ -0,0 +1,324 @@
+//! The solutions for other languages: the library is also built as a C shared library
+//! (`libaoc_2022_rust.so`), and `include/aoc.h` declares its functions.
+//!
+//! The header is generated from this file by the tests, with cbindgen: a test fails and
+//! rewrites it when it is out of date.
+
+use std::ffi::{c_char, CStr, CString};
+use std::panic::AssertUnwindSafe;
+
+use crate::runner::{panic_message, parse_params, solver, solver_with, Params, Part};
+
+/// What [`aoc_solve`] tells about the string it gives.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AocStatus {
+    /// the string is the answer
+    Ok = 0,
+    /// no solution for the day
+    UnknownDay = 1,
+    /// the part is neither 1 nor 2
+    UnknownPart = 2,
+    /// the input is not UTF-8, or the day can't parse it
+    InvalidInput = 3,
+    /// the parameters are malformed, or unknown to the day
+    InvalidParams = 4,
+    /// a pointer is null where it can't be
+    NullPointer = 5,
+    /// the solution panicked on the input
+    Panicked = 6,
+}
+
+/// Solves `part` (1 or 2) of `day` on the `len` bytes of `input`.
+///
+/// `params` is null, or gives the puzzle parameters that differ from the real puzzle, like
+/// `row=10&search_limit=20` for the sample of day 15.
+///
+/// `*output` receives the answer when the status is `Ok`, or else the error, as a string that
+/// belongs to the caller and is freed with [`aoc_free_string`]. Only a null `output` is left
+/// as is. Answers drawn with letters, like the one of day 10, span several lines.
+///
+/// # Safety
+///
+/// `input` points to `len` bytes (it may be null when `len` is 0), `params` is null or a
+/// nul-terminated string, and `output` is null or points to a writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    len: usize,
+    params: *const c_char,
+    output: *mut *mut c_char,
+) -> AocStatus {
+    if output.is_null() {
+        return AocStatus::NullPointer;
+    }
+    let solved =
+        std::panic::catch_unwind(AssertUnwindSafe(|| solve(day, part, input, len, params)));
+    let (status, text) = match solved {
+        Ok(Ok(answer)) => (AocStatus::Ok, answer),
+        Ok(Err(error)) => error,
+        Err(panic) => (
+            AocStatus::Panicked,
+            format!("the solution panicked: {}", panic_message(&*panic)),
+        ),
+    };
+    // neither the answers nor the errors have nul bytes, but the input could
+    let text = CString::new(text.replace('\0', "")).unwrap();
+    *output = text.into_raw();
+    status
+}
+
+/// Frees a string given by [`aoc_solve`]. Null is ignored.
+///
+/// # Safety
+///
+/// `string` is null, or a string given by [`aoc_solve`] and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free_string(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// # Safety
+///
+/// Same as [`aoc_solve`].
+unsafe fn solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    len: usize,
+    params: *const c_char,
+) -> Result<String, (AocStatus, String)> {
+    if solver(day).is_none() {
+        return Err((AocStatus::UnknownDay, format!("no solution for day {day}")));
+    }
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err((AocStatus::UnknownPart, format!("no part {part}"))),
+    };
+    let params = if params.is_null() {
+        Params::new()
+    } else {
+        let params = CStr::from_ptr(params).to_str().map_err(|_| {
+            let message = String::from("parameters not in UTF-8");
+            (AocStatus::InvalidParams, message)
+        })?;
+        parse_params(params).map_err(|error| (AocStatus::InvalidParams, error))?
+    };
+    let solver = solver_with(day, &params).map_err(|error| (AocStatus::InvalidParams, error))?;
+
+    let bytes = match (input.is_null(), len) {
+        (_, 0) => &[][..],
+        (true, _) => {
+            let message = format!("null input of {len} bytes");
+            return Err((AocStatus::NullPointer, message));
+        }
+        (false, _) => std::slice::from_raw_parts(input, len),
+    };
+    let data = std::str::from_utf8(bytes).map_err(|error| {
+        let message = format!("input not in UTF-8: {error}");
+        (AocStatus::InvalidInput, message)
+    })?;
+    let mut solved = solver
+        .solve(data, &[part])
+        .map_err(|error| (AocStatus::InvalidInput, error.diagnostic(data)))?;
+    Ok(solved.answers.remove(0).value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{aoc_free_string, aoc_solve, AocStatus};
+    use crate::runner::DAYS;
+    use std::ffi::{c_char, CStr};
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+
+    const HEADER: &str = "include/aoc.h";
+
+    /// Calls [`aoc_solve`] like a C program would.
+    fn solve(day: u8, part: u8, input: &[u8], params: Option<&CStr>) -> (AocStatus, String) {
+        let mut output: *mut c_char = std::ptr::null_mut();
+        let params = params.map_or(std::ptr::null(), CStr::as_ptr);
+        // SAFETY: valid pointers
+        unsafe {
+            let status = aoc_solve(day, part, input.as_ptr(), input.len(), params, &mut output);
+            let text = CStr::from_ptr(output).to_string_lossy().into_owned();
+            aoc_free_string(output);
+            (status, text)
+        }
+    }
+
+    #[test]
+    fn answers_or_errors() {
+        let sample = include_bytes!("../inputs/day07.sample.txt");
+        assert_eq!(
+            solve(7, 1, sample, None),
+            (AocStatus::Ok, String::from("95437"))
+        );
+        let sample = include_bytes!("../inputs/day15.sample.txt");
+        assert_eq!(
+            solve(15, 1, sample, Some(c"row=10")),
+            (AocStatus::Ok, String::from("26"))
+        );
+
+        assert_eq!(solve(DAYS.end() + 1, 1, b"", None).0, AocStatus::UnknownDay);
+        assert_eq!(solve(7, 3, sample, None).0, AocStatus::UnknownPart);
+        assert_eq!(
+            solve(15, 1, sample, Some(c"rows=10")).0,
+            AocStatus::InvalidParams
+        );
+        assert_eq!(solve(1, 1, b"\xff", None).0, AocStatus::InvalidInput);
+        let (status, error) = solve(1, 1, b"12\nlots\n", None);
+        assert_eq!(status, AocStatus::InvalidInput);
+        assert!(error.contains("line 2"), "{error}");
+
+        let (status, error) = solve(1, 1, &[], None);
+        assert_eq!(status, AocStatus::InvalidInput);
+        assert!(error.contains("calories"), "{error}");
+        // SAFETY: null pointers are allowed
+        unsafe {
+            let mut output = std::ptr::null_mut();
+            let status = aoc_solve(1, 1, std::ptr::null(), 4, std::ptr::null(), &mut output);
+            assert_eq!(status, AocStatus::NullPointer);
+            aoc_free_string(output);
+            let status = aoc_solve(
+                1,
+                1,
+                b"1".as_ptr(),
+                1,
+                std::ptr::null(),
+                std::ptr::null_mut(),
+            );
+            assert_eq!(status, AocStatus::NullPointer);
+            aoc_free_string(std::ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn header_is_up_to_date() {
+        let config = cbindgen::Config {
+            language: cbindgen::Language::C,
+            header: Some(String::from(
+                "/* The solutions of Advent of Code 2022, from libaoc_2022_rust. */",
+            )),
+            autogen_warning: Some(String::from(
+                "/* Generated from src/ffi.rs by `cargo test`: don't edit by hand. */",
+            )),
+            include_guard: Some(String::from("AOC_H")),
+            cpp_compat: true,
+            usize_is_size_t: true,
+            documentation_style: cbindgen::DocumentationStyle::C,
+            enumeration: cbindgen::EnumConfig {
+                rename_variants: cbindgen::RenameRule::QualifiedScreamingSnakeCase,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut header = vec![];
+        cbindgen::Builder::new()
+            .with_config(config)
+            .with_src("src/ffi.rs")
+            .generate()
+            .unwrap()
+            .write(&mut header);
+        let header = String::from_utf8(header).unwrap();
+        if std::fs::read_to_string(HEADER).ok().as_ref() != Some(&header) {
+            std::fs::write(HEADER, header).unwrap();
+            panic!("{HEADER} was out of date: it is rewritten, to be committed");
+        }
+    }
+
+    /// The shared library, built by cargo next to the test binary.
+    fn library_dir() -> PathBuf {
+        let dir = std::env::current_exe()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .to_path_buf();
+        let library = format!(
+            "{}aoc_2022_rust{}",
+            std::env::consts::DLL_PREFIX,
+            std::env::consts::DLL_SUFFIX
+        );
+        assert!(
+            dir.join(&library).exists(),
+            "no {library} in {}: build it with `cargo build --lib`",
+            dir.display()
+        );
+        dir
+    }
+
+    /// `c/samples.c`, built with the C compiler (`cc`, or the one in `CC`) against the shared
+    /// library, solves the samples of `inputs/samples.json`.
+    #[cfg(unix)]
+    #[test]
+    fn c_program_solves_the_samples() {
+        let library_dir = library_dir();
+        let program = crate::stand_in::temp_dir("ffi").join("samples");
+        let compiler = std::env::var("CC").unwrap_or_else(|_| String::from("cc"));
+        let compiled = Command::new(&compiler)
+            .args([
+                "-std=c99",
+                "-Wall",
+                "-Wextra",
+                "-Werror",
+                "-Iinclude",
+                "c/samples.c",
+            ])
+            .arg("-o")
+            .arg(&program)
+            .arg(format!("-L{}", library_dir.display()))
+            .arg(format!("-Wl,-rpath,{}", library_dir.display()))
+            .arg("-laoc_2022_rust")
+            .output()
+            .unwrap_or_else(|error| panic!("cannot run {compiler}: {error}"));
+        assert!(
+            compiled.status.success(),
+            "{}",
+            String::from_utf8_lossy(&compiled.stderr)
+        );
+
+        let mut days = vec![];
+        for sample in crate::samples::manifest() {
+            days.push(sample.day);
+            for (part, expected) in [(1, &sample.part1), (2, &sample.part2)] {
+                let Some(expected) = expected else {
+                    continue;
+                };
+                let params: Vec<String> = sample
+                    .params
+                    .iter()
+                    .map(|(name, value)| format!("{name}={value}"))
+                    .collect();
+                let run = Command::new(&program)
+                    .arg(sample.day.to_string())
+                    .arg(part.to_string())
+                    .arg(Path::new("inputs").join(&sample.file))
+                    .arg(params.join("&"))
+                    .output()
+                    .unwrap();
+                let name = format!(
+                    "day {} part {part} on {}",
+                    sample.day,
+                    sample.file.display()
+                );
+                assert!(
+                    run.status.success(),
+                    "{name}: {}",
+                    String::from_utf8_lossy(&run.stderr)
+                );
+                assert_eq!(
+                    String::from_utf8_lossy(&run.stdout),
+                    format!("{expected}\n"),
+                    "{name}"
+                );
+            }
+        }
+        days.dedup();
+        assert_eq!(days, DAYS.collect::<Vec<u8>>());
+    }
+}
@@ -0,0 +1,465 @@
+//! Random puzzle inputs, in the format of each day, to push the solutions further than the real
+//! inputs.
+//!
+//! The inputs are not only well formed: they also keep the promises of the puzzle statements
+//! that the solutions rely on (there is a path to the best signal, the filesystem is not too
+//! full for the update, ...), so that any failure is a bug of the solution.
+
+use std::fmt::Write;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// A random input for `day`, about `size` items long (elves, lines, directories, monkey items,
+/// ...), always the same for a given `seed`.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+    let input = match day {
+        1 => day01(size, rng),
+        2 => day02(size, rng),
+        3 => day03(size, rng),
+        4 => day04(size, rng),
+        5 => day05(size, rng),
+        6 => day06(size, rng),
+        7 => day07(size, rng),
+        8 => day08(size, rng),
+        9 => day09(size, rng),
+        10 => day10(size, rng),
+        11 => day11(size, rng),
+        12 => day12(size, rng),
+        13 => day13(size, rng),
+        14 => day14(size, rng),
+        15 => day15(size, rng),
+        _ => return None,
+    };
+    Some(input)
+}
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `size` elves carrying a few snacks each.
+fn day01(size: usize, rng: &mut StdRng) -> String {
+    let elves: Vec<String> = (0..size)
+        .map(|_| {
+            let snacks: Vec<String> = (0..rng.gen_range(1..=15))
+                .map(|_| rng.gen_range(1000..=70_000).to_string())
+                .collect();
+            snacks.join("\n")
+        })
+        .collect();
+    elves.join("\n\n") + "\n"
+}
+
+/// `size` rounds of rock paper scissors.
+fn day02(size: usize, rng: &mut StdRng) -> String {
+    (0..size).fold(String::new(), |mut input, _| {
+        let opponent = b"ABC"[rng.gen_range(0..3)] as char;
+        let ours = b"XYZ"[rng.gen_range(0..3)] as char;
+        let _ = writeln!(input, "{opponent} {ours}");
+        input
+    })
+}
+
+/// `size` rucksacks (rounded up to whole groups of three), each with a single item in both
+/// compartments, and a single badge shared by each group.
+fn day03(size: usize, rng: &mut StdRng) -> String {
+    let mut input = String::new();
+    for _ in 0..size.div_ceil(3) {
+        let mut letters = LETTERS.to_vec();
+        letters.shuffle(rng);
+        let badge = letters.pop().unwrap();
+        // each elf of the group has its own 17 letters, so that only the badge is shared
+        for own in letters.chunks(17) {
+            let (shared, pools) = own.split_first().unwrap();
+            let (first_pool, second_pool) = pools.split_at(8);
+            let half = rng.gen_range(2..=20);
+            let mut first = vec![*shared, badge];
+            let mut second = vec![*shared];
+            first.extend((2..half).map(|_| *first_pool.choose(rng).unwrap()));
+            second.extend((1..half).map(|_| *second_pool.choose(rng).unwrap()));
+            first.shuffle(rng);
+            second.shuffle(rng);
+            first.extend(second);
+            input.push_str(std::str::from_utf8(&first).unwrap());
+            input.push('\n');
+        }
+    }
+    input
+}
+
+/// `size` pairs of section assignments.
+fn day04(size: usize, rng: &mut StdRng) -> String {
+    let range = |rng: &mut StdRng| {
+        let start = rng.gen_range(1..=99);
+        (start, rng.gen_range(start..=99))
+    };
+    (0..size).fold(String::new(), |mut input, _| {
+        let (a, b) = range(rng);
+        let (c, d) = range(rng);
+        let _ = writeln!(input, "{a}-{b},{c}-{d}");
+        input
+    })
+}
+
+/// Up to 9 stacks of crates, then `size` moves that never take more crates than a stack has.
+fn day05(size: usize, rng: &mut StdRng) -> String {
+    let nb_stacks = rng.gen_range(3..=9);
+    let stacks: Vec<Vec<char>> = (0..nb_stacks)
+        .map(|_| {
+            (0..rng.gen_range(1..=8))
+                .map(|_| LETTERS[rng.gen_range(26..52)] as char)
+                .collect()
+        })
+        .collect();
+
+    let mut input = String::new();
+    let max_height = stacks.iter().map(Vec::len).max().unwrap();
+    for level in (0..max_height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => String::from("   "),
+            })
+            .collect();
+        input.push_str(&row.join(" "));
+        input.push('\n');
+    }
+    let labels: Vec<String> = (1..=nb_stacks).map(|n| format!(" {n} ")).collect();
+    input.push_str(&labels.join(" "));
+    input.push_str("\n\n");
+
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    for _ in 0..size {
+        let from = loop {
+            let from = rng.gen_range(0..nb_stacks);
+            if heights[from] > 0 {
+                break from;
+            }
+        };
+        let to = (from + rng.gen_range(1..nb_stacks)) % nb_stacks;
+        let nb = rng.gen_range(1..=heights[from]);
+        heights[from] -= nb;
+        heights[to] += nb;
+        let _ = writeln!(input, "move {nb} from {} to {}", from + 1, to + 1);
+    }
+    input
+}
+
+/// A datastream of `size` characters (at least 14), with a start-of-message marker somewhere.
+fn day06(size: usize, rng: &mut StdRng) -> String {
+    let size = size.max(14);
+    // few distinct letters, so that markers are rare
+    let alphabet = &LOWERCASE[..rng.gen_range(8..=14)];
+    let mut stream: Vec<u8> = (0..size).map(|_| *alphabet.choose(rng).unwrap()).collect();
+    let mut marker = LOWERCASE.to_vec();
+    marker.shuffle(rng);
+    let start = rng.gen_range(0..=size - 14);
+    stream[start..start + 14].copy_from_slice(&marker[..14]);
+    String::from_utf8(stream).unwrap() + "\n"
+}
+
+/// The terminal output of browsing a filesystem of `size` directories, which uses between
+/// 40_000_000 and 70_000_000 so that the update can be made room for.
+fn day07(size: usize, rng: &mut StdRng) -> String {
+    struct Dir {
+        name: String,
+        files: Vec<(String, usize)>,
+        dirs: Vec<usize>,
+    }
+    let name = |rng: &mut StdRng, max_len: usize| -> String {
+        (0..rng.gen_range(1..=max_len))
+            .map(|_| *LOWERCASE.choose(rng).unwrap() as char)
+            .collect()
+    };
+
+    // a tree of directories, each one added in a random existing directory
+    let mut dirs = vec![Dir {
+        name: String::from("/"),
+        files: vec![],
+        dirs: vec![],
+    }];
+    for idx in 1..size {
+        let parent = rng.gen_range(0..idx);
+        dirs.push(Dir {
+            name: format!("{}{idx}", name(rng, 6)),
+            files: vec![],
+            dirs: vec![],
+        });
+        dirs[parent].dirs.push(idx);
+    }
+    // random weights, scaled to the total size
+    let mut weights = vec![];
+    for (idx, dir) in dirs.iter_mut().enumerate() {
+        for file in 0..rng.gen_range(if idx == 0 { 1 } else { 0 }..=5) {
+            let extension = ["", ".txt", ".log", ".dat", ".lst"].choose(rng).unwrap();
+            let file_name = format!("{}{file}{extension}", name(rng, 8));
+            weights.push(rng.gen_range(1..=1000));
+            dir.files.push((file_name, 0));
+        }
+    }
+    let total: usize = rng.gen_range(40_000_001..70_000_000);
+    let sum_weights: usize = weights.iter().sum();
+    let mut weights = weights.into_iter();
+    for dir in dirs.iter_mut() {
+        for (_, file_size) in dir.files.iter_mut() {
+            *file_size = (total * weights.next().unwrap() / sum_weights).max(1);
+        }
+    }
+
+    fn browse(dirs: &[Dir], idx: usize, input: &mut String) {
+        let dir = &dirs[idx];
+        let _ = writeln!(input, "$ cd {}", dir.name);
+        input.push_str("$ ls\n");
+        for &sub in &dir.dirs {
+            let _ = writeln!(input, "dir {}", dirs[sub].name);
+        }
+        for (name, size) in &dir.files {
+            let _ = writeln!(input, "{size} {name}");
+        }
+        for &sub in &dir.dirs {
+            browse(dirs, sub, input);
+        }
+        if idx != 0 {
+            input.push_str("$ cd ..\n");
+        }
+    }
+    let mut input = String::new();
+    browse(&dirs, 0, &mut input);
+    input
+}
+
+/// A square forest of `size` by `size` trees.
+fn day08(size: usize, rng: &mut StdRng) -> String {
+    (0..size).fold(String::new(), |mut input, _| {
+        input.extend((0..size).map(|_| char::from(b'0' + rng.gen_range(0..10))));
+        input.push('\n');
+        input
+    })
+}
+
+/// `size` moves of the head of the rope.
+fn day09(size: usize, rng: &mut StdRng) -> String {
+    (0..size).fold(String::new(), |mut input, _| {
+        let direction = ["R", "L", "U", "D"].choose(rng).unwrap();
+        let _ = writeln!(input, "{direction} {}", rng.gen_range(1..=20));
+        input
+    })
+}
+
+/// `size` instructions of the CPU.
+fn day10(size: usize, rng: &mut StdRng) -> String {
+    (0..size).fold(String::new(), |mut input, _| {
+        if rng.gen_bool(0.3) {
+            input.push_str("noop\n");
+        } else {
+            let _ = writeln!(input, "addx {}", rng.gen_range(-20..=20));
+        }
+        input
+    })
+}
+
+/// `size` items (at least one per monkey) shared by up to 8 monkeys.
+///
+/// The divisors are distinct primes up to 19, and the operations never multiply by more than
+/// 3: the worry levels have no modulo in part 1, only the division by 3 keeps them in 64 bits.
+fn day11(size: usize, rng: &mut StdRng) -> String {
+    let nb_monkeys = size.clamp(2, 8);
+    let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19];
+    divisors.shuffle(rng);
+    // every monkey starts with an item, like in the puzzle inputs
+    let mut items = vec![vec![]; nb_monkeys];
+    for idx in 0..size.max(nb_monkeys) {
+        let monkey = if idx < nb_monkeys {
+            idx
+        } else {
+            rng.gen_range(0..nb_monkeys)
+        };
+        items[monkey].push(rng.gen_range(40..=99).to_string());
+    }
+
+    let monkeys: Vec<String> = (0..nb_monkeys)
+        .map(|idx| {
+            let operation = if rng.gen_bool(0.5) {
+                format!("old * {}", rng.gen_range(2..=3))
+            } else {
+                format!("old + {}", rng.gen_range(1..=8))
+            };
+            let mut target = || (idx + rng.gen_range(1..nb_monkeys)) % nb_monkeys;
+            let (if_true, if_false) = (target(), target());
+            format!(
+                "Monkey {idx}:\n  Starting items: {}\n  Operation: new = {operation}\n  \
+                 Test: divisible by {}\n    If true: throw to monkey {if_true}\n    \
+                 If false: throw to monkey {if_false}\n",
+                items[idx].join(", "),
+                divisors[idx]
+            )
+        })
+        .collect();
+    monkeys.join("\n")
+}
+
+/// A heightmap of `size` rows and at least 26 columns, rising from the start on the left to
+/// the best signal on the right. The row of the start climbs one step at a time; the other
+/// cells may be lower.
+fn day12(size: usize, rng: &mut StdRng) -> String {
+    let width = size.max(26);
+    let height = size.max(2);
+    let path_row = rng.gen_range(0..height);
+    (0..height).fold(String::new(), |mut input, y| {
+        for x in 0..width {
+            let column_height = (x * 25 / (width - 1)) as u8;
+            let ch = match x {
+                0 if y == path_row => 'S',
+                _ if y == path_row && x == width - 1 => 'E',
+                _ if y == path_row => char::from(b'a' + column_height),
+                _ => char::from(b'a' + rng.gen_range(0..=column_height)),
+            };
+            input.push(ch);
+        }
+        input.push('\n');
+        input
+    })
+}
+
+/// `size` pairs of packets.
+fn day13(size: usize, rng: &mut StdRng) -> String {
+    fn packet(rng: &mut StdRng, depth: usize) -> String {
+        let items: Vec<String> = (0..rng.gen_range(0..=5))
+            .map(|_| {
+                if depth < 4 && rng.gen_bool(0.3) {
+                    packet(rng, depth + 1)
+                } else {
+                    rng.gen_range(0..=10).to_string()
+                }
+            })
+            .collect();
+        format!("[{}]", items.join(","))
+    }
+    let pairs: Vec<String> = (0..size)
+        .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+        .collect();
+    pairs.join("\n")
+}
+
+/// `size` paths of rock below the source of sand at `500,0`.
+fn day14(size: usize, rng: &mut StdRng) -> String {
+    // the cave has to fit in 1000x1000
+    let depth = 10 + size.min(400) as isize;
+    (0..size).fold(String::new(), |mut input, _| {
+        let mut point = (
+            500 + rng.gen_range(-depth..=depth),
+            rng.gen_range(1..=depth),
+        );
+        let mut points = vec![format!("{},{}", point.0, point.1)];
+        for segment in 0..rng.gen_range(1..=4) {
+            let length = rng.gen_range(1..=8);
+            if segment % 2 == 0 {
+                point.0 += if rng.gen_bool(0.5) { length } else { -length };
+            } else {
+                point.1 = (point.1 + rng.gen_range(-length..=length)).clamp(1, depth);
+            }
+            points.push(format!("{},{}", point.0, point.1));
+        }
+        input.push_str(&points.join(" -> "));
+        input.push('\n');
+        input
+    })
+}
+
+/// Largest coordinate of the distress beacon, as searched by part 2.
+const DAY15_LIMIT: i64 = 4_000_000;
+
+/// About `size` sensors, whose ranges all stop just short of the distress beacon: it is drawn
+/// first, then four sensors far in the corners cover the rest of the search area.
+fn day15(size: usize, rng: &mut StdRng) -> String {
+    let free = (
+        rng.gen_range(0..=DAY15_LIMIT),
+        rng.gen_range(0..=DAY15_LIMIT),
+    );
+    let mut reports = vec![];
+    for (sx, sy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        // covers the quarter of the search area on this side of the free cell, but the cell
+        let reach = |free: i64, side: i64| {
+            let reach = if side > 0 { DAY15_LIMIT - free } else { free };
+            reach.max(1)
+        };
+        let dx = rng.gen_range(reach(free.0, sx)..=reach(free.0, sx) + 1_000_000);
+        let dy = rng.gen_range(reach(free.1, sy)..=reach(free.1, sy) + 1_000_000);
+        let sensor = (free.0 + sx * dx, free.1 + sy * dy);
+        reports.push((sensor, (free.0 + sx, free.1)));
+    }
+    while reports.len() < size {
+        let sensor = (
+            rng.gen_range(0..=DAY15_LIMIT),
+            rng.gen_range(0..=DAY15_LIMIT),
+        );
+        let range = (sensor.0 - free.0).abs() + (sensor.1 - free.1).abs() - 1;
+        if range < 1 {
+            continue;
+        }
+        let dx = rng.gen_range(-range..=range);
+        let dy = (range - dx.abs()) * if rng.gen_bool(0.5) { 1 } else { -1 };
+        reports.push((sensor, (sensor.0 + dx, sensor.1 + dy)));
+    }
+    reports.shuffle(rng);
+    reports
+        .into_iter()
+        .fold(String::new(), |mut input, (sensor, beacon)| {
+            let _ = writeln!(
+                input,
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sensor.0, sensor.1, beacon.0, beacon.1
+            );
+            input
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::{generate, DAY15_LIMIT};
+    use crate::runner::{solver, Part, DAYS};
+
+    #[test]
+    fn same_seed_same_input() {
+        assert_eq!(generate(13, 20, 7), generate(13, 20, 7));
+        assert_ne!(generate(13, 20, 7), generate(13, 20, 8));
+        assert_eq!(generate(99, 20, 7), None);
+    }
+
+    #[test]
+    fn every_day_solves_its_inputs() {
+        for day in DAYS {
+            // part 1 of day 15 scans a row 2_000_000 wide, too slow for a test
+            let parts: &[Part] = if day == 15 { &[Part::Two] } else { &Part::BOTH };
+            for (size, seed) in [(1, 1), (10, 2), (40, 3)] {
+                let data = generate(day, size, seed).unwrap();
+                let solved = solver(day).unwrap().solve(&data, parts);
+                if let Err(error) = solved {
+                    panic!("size {size}, seed {seed}: {}", error.diagnostic(&data));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn day15_hides_a_single_distress_beacon() {
+        for seed in 1..=4 {
+            let data = generate(15, 2000, seed).unwrap();
+            let solved = solver(15).unwrap().solve(&data, &[Part::Two]).unwrap();
+            // the distress beacon is the first thing drawn
+            let rng = &mut StdRng::seed_from_u64(seed);
+            let (x, y): (i64, i64) = (
+                rng.gen_range(0..=DAY15_LIMIT),
+                rng.gen_range(0..=DAY15_LIMIT),
+            );
+            assert_eq!(solved.answers[0].value, (x * 4_000_000 + y).to_string());
+        }
+    }
+}
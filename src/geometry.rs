@@ -0,0 +1,287 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::parse::{number, split_pair, Expected};
+
+/// A point of the plane, or the vector between two points.
+///
+/// `y` grows downwards, like the rows of the puzzle maps: [`Direction::Up`] decreases it.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Difference between two points.
+pub type Vector = Point<isize>;
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Point<T> {
+    /// Number of horizontal and vertical steps between the two points.
+    pub fn manhattan(self, other: Self) -> T {
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+
+    /// Number of steps between the two points when moving diagonally is allowed too.
+    pub fn chebyshev(self, other: Self) -> T {
+        distance(self.x, other.x).max(distance(self.y, other.y))
+    }
+}
+
+fn distance<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl Point<isize> {
+    /// The vector of length 1 (or 0) on each axis pointing the same way.
+    pub fn signum(self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The same point with unsigned coordinates, if none is negative.
+    pub fn unsigned(self) -> Option<Point<usize>> {
+        Some(Point::new(
+            usize::try_from(self.x).ok()?,
+            usize::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl Point<usize> {
+    /// The same point with signed coordinates, to compute vectors.
+    pub fn signed(self) -> Point<isize> {
+        Point::new(self.x as isize, self.y as isize)
+    }
+
+    /// The point one `step` away, if it doesn't go below 0 on an axis.
+    pub fn checked_add(self, step: Vector) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_add_signed(step.x)?,
+            self.y.checked_add_signed(step.y)?,
+        ))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// The `x,y` notation of the puzzle inputs.
+impl<T: FromStr> FromStr for Point<T> {
+    type Err = Expected;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (xs, ys) = split_pair(s, ",", "`,` between the coordinates")?;
+        Ok(Point::new(number(s, xs)?, number(s, ys)?))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, from the top.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The step to the next point in this direction.
+    pub fn delta(self) -> Vector {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+/// The `U`, `R`, `D` and `L` letters of the puzzle inputs.
+impl FromStr for Direction {
+    type Err = Expected;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Direction::Up),
+            "R" => Ok(Direction::Right),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            _ => Err(Expected::at(1, "a direction (R, L, U or D)")),
+        }
+    }
+}
+
+/// The smallest rectangle containing some points, borders included.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Bounds<T = isize> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Copy + Ord> Bounds<T> {
+    /// The bounds of the given points, if there is any.
+    pub fn of(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = Bounds {
+            min: first,
+            max: first,
+        };
+        for point in points {
+            bounds.extend(point);
+        }
+        Some(bounds)
+    }
+
+    /// Grows the bounds to contain `point`.
+    pub fn extend(&mut self, point: Point<T>) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Bounds, Direction, Point};
+    use crate::parse::Expected;
+
+    #[test]
+    fn arithmetic() {
+        let p = Point::new(3, -2);
+        assert_eq!(p + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(p - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(p * 2, Point::new(6, -4));
+        assert_eq!(-p, Point::new(-3, 2));
+        assert_eq!(Point::new(-5, 2).signum(), Point::new(-1, 1));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point::new(8, 7);
+        let b = Point::new(2, 10);
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Point::<usize>::new(0, 5).manhattan(Point::new(3, 1)), 7);
+    }
+
+    #[test]
+    fn rotations() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.delta() + direction.opposite().delta(),
+                Point::default()
+            );
+        }
+    }
+
+    #[test]
+    fn parse_coordinates() {
+        assert_eq!("498,4".parse(), Ok(Point::<usize>::new(498, 4)));
+        assert_eq!("-2,15".parse(), Ok(Point::<isize>::new(-2, 15)));
+        assert_eq!(
+            "498;4".parse::<Point>(),
+            Err(Expected::at(6, "`,` between the coordinates"))
+        );
+        assert_eq!("498,x".parse::<Point>(), Err(Expected::at(5, "a number")));
+    }
+
+    #[test]
+    fn bounds_of_points() {
+        let bounds = Bounds::of([Point::new(2, 3), Point::new(-1, 5), Point::new(0, 0)]).unwrap();
+        assert_eq!(bounds.min, Point::new(-1, 0));
+        assert_eq!(bounds.max, Point::new(2, 5));
+        assert!(bounds.contains(Point::new(0, 4)));
+        assert!(!bounds.contains(Point::new(3, 4)));
+        assert_eq!(Bounds::<isize>::of([]), None);
+    }
+}
@@ -0,0 +1,266 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{Direction, Point, Vector};
+use crate::parse::{Expected, ParseError};
+
+/// Position in a grid: column then row, from the top left corner.
+pub type Pos = Point<usize>;
+
+/// Steps to the neighbours sharing a side or a corner with a cell, clockwise from the top.
+pub const DIRECTIONS8: [Vector; 8] = [
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+    Point::new(-1, -1),
+];
+
+/// A rectangle of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a map with a character per cell, like most of the puzzle inputs.
+    ///
+    /// Every line is a row, and they must all have the same width. `cell` turns the character at
+    /// a position into a cell, or tells what it expected instead.
+    pub fn parse<E: Into<String>>(
+        day: u8,
+        data: &str,
+        mut cell: impl FnMut(Pos, char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = vec![];
+        let mut height = 0;
+        for (y, line) in data.lines().enumerate() {
+            let error =
+                |column, expected: String| ParseError::new(day, y, Expected::at(column, expected));
+            let len = line.chars().count();
+            let width = *width.get_or_insert(len);
+            if len == 0 || len != width {
+                let expected = format!("a row of {} cells", width.max(1));
+                let end = line
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.len(), |(at, _)| at);
+                return Err(error(end + 1, expected));
+            }
+            for (x, (at, ch)) in line.char_indices().enumerate() {
+                cells.push(
+                    cell(Point::new(x, y), ch)
+                        .map_err(|expected| error(at + 1, expected.into()))?,
+                );
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            None => Err(ParseError::new(day, 0, Expected::at(1, "a row of cells"))),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    /// The cell one `step` away from `pos`, if it is in the grid.
+    pub fn step(&self, pos: Pos, step: Vector) -> Option<Pos> {
+        pos.checked_add(step).filter(|&next| self.contains(next))
+    }
+
+    /// The neighbours of `pos` sharing a side with it.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction.delta()))
+    }
+
+    /// The neighbours of `pos` sharing a side or a corner with it.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS8
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The positions from `pos` (excluded) to the edge of the grid, a `step` at a time.
+    pub fn ray(&self, pos: Pos, step: Vector) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, step), move |&pos| self.step(pos, step))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(
+            x < self.width,
+            "column {x} is out of a grid of {}x{}",
+            self.width,
+            self.height
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width)
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is out of a grid of {}x{}", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is out of a grid of {width}x{height}"))
+    }
+}
+
+/// Draws the grid with the `Display` of each cell, a line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Grid, Pos};
+    use crate::geometry::Point;
+    use crate::parse::ParseError;
+
+    fn digits(data: &str) -> Grid<u32> {
+        Grid::parse(0, data, |_, ch| ch.to_digit(10).ok_or("a digit")).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 0)], 3);
+        assert_eq!(grid[Point::new(0, 1)], 4);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn parse_errors_are_located() {
+        let error = |data| Grid::parse(0, data, |_, ch| ch.to_digit(10).ok_or("a digit"));
+        let at = |line, column, expected: &str| ParseError {
+            day: 0,
+            line,
+            column,
+            expected: String::from(expected),
+        };
+        assert_eq!(error("123\n4x6"), Err(at(2, 2, "a digit")));
+        assert_eq!(error("123\n45"), Err(at(2, 3, "a row of 3 cells")));
+        assert_eq!(error("123\n4567"), Err(at(2, 4, "a row of 3 cells")));
+        assert_eq!(error(""), Err(at(1, 1, "a row of cells")));
+        // columns count bytes, like in every parse error
+        assert_eq!(error("1é3\n4x6"), Err(at(1, 2, "a digit")));
+        assert_eq!(error("123\né5x"), Err(at(2, 1, "a digit")));
+        assert_eq!(error("123\n45\n7"), Err(at(2, 3, "a row of 3 cells")));
+        let error = |data| Grid::parse(0, data, |_, ch| Ok::<char, &str>(ch));
+        assert_eq!(error("aé\nbçd"), Err(at(2, 4, "a row of 2 cells")));
+        assert_eq!(error("aé\nb"), Err(at(2, 2, "a row of 2 cells")));
+    }
+
+    #[test]
+    fn neighbours_stay_in_the_grid() {
+        let grid = digits("123\n456\n789");
+        let corner: Vec<Pos> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Point::new(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn rows_columns_and_rays() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+        let ray: Vec<u32> = grid
+            .ray(Point::new(2, 2), Point::new(-1, -1))
+            .map(|pos| grid[pos])
+            .collect();
+        assert_eq!(ray, vec![5, 1]);
+        assert_eq!(grid.ray(Point::new(0, 1), Point::new(-1, 0)).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is out of a grid of 3x3")]
+    fn column_out_of_the_grid() {
+        let grid = digits("123\n456\n789");
+        let _ = grid.column(3);
+    }
+}
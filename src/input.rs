@@ -0,0 +1,194 @@
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// the conventional `inputs/dayNN.txt`
+    Default,
+    /// `inputs/<profile>/dayNN.txt`, the input of another account
+    Profile(String),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// `-` stands for stdin, like most command line tools.
+    pub fn from_arg(arg: Option<PathBuf>) -> Self {
+        match arg {
+            None => Source::Default,
+            Some(path) if path.as_os_str() == "-" => Source::Stdin,
+            Some(path) => Source::File(path),
+        }
+    }
+
+    /// The usual input of a profile, or the default one without a profile.
+    pub fn from_profile(profile: Option<String>) -> Self {
+        profile.map_or(Source::Default, Source::Profile)
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing {
+        path: PathBuf,
+    },
+    Unreadable {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Stdin(std::io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing { path } => write!(
+                f,
+                "no puzzle input at {} (give another path with --input, or - to read stdin)",
+                path.display()
+            ),
+            InputError::Unreadable { path, source } => {
+                write!(f, "can't read {}: {source}", path.display())
+            }
+            InputError::Stdin(source) => write!(f, "can't read stdin: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Unreadable { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+/// Directory of the puzzle inputs.
+pub const DIR: &str = "inputs";
+
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(DIR).join(file_name(day))
+}
+
+/// Directory of the puzzle inputs of a profile.
+pub fn profile_dir(profile: &str) -> PathBuf {
+    Path::new(DIR).join(profile)
+}
+
+/// Checks that a profile name is a plain directory name, for the command line.
+pub fn profile_name(name: &str) -> Result<String, String> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '.');
+    if valid {
+        Ok(String::from(name))
+    } else {
+        Err(String::from(
+            "a profile is named with letters, digits, `-`, `_` and `.`",
+        ))
+    }
+}
+
+/// The profiles found in `dir`: its subdirectories, by name.
+pub fn profiles(dir: &Path) -> Result<Vec<String>, InputError> {
+    let unreadable = |source| InputError::Unreadable {
+        path: dir.to_path_buf(),
+        source,
+    };
+    let mut profiles = vec![];
+    for entry in std::fs::read_dir(dir).map_err(unreadable)? {
+        let entry = entry.map_err(unreadable)?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if entry.file_type().map_err(unreadable)?.is_dir() && profile_name(&name).is_ok() {
+            profiles.push(name);
+        }
+    }
+    profiles.sort();
+    Ok(profiles)
+}
+
+/// Name of the puzzle input of `day` in its directory.
+pub fn file_name(day: u8) -> String {
+    format!("day{day:02}.txt")
+}
+
+/// Reads the puzzle input of `day` from `source`.
+pub fn load(day: u8, source: &Source) -> Result<String, InputError> {
+    match source {
+        Source::Default => read_file(&default_path(day)),
+        Source::Profile(profile) => read_file(&profile_dir(profile).join(file_name(day))),
+        Source::File(path) => read_file(path),
+        Source::Stdin => {
+            let mut data = String::new();
+            std::io::stdin()
+                .read_to_string(&mut data)
+                .map_err(InputError::Stdin)?;
+            Ok(data)
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|source| match source.kind() {
+        std::io::ErrorKind::NotFound => InputError::Missing {
+            path: path.to_path_buf(),
+        },
+        _ => InputError::Unreadable {
+            path: path.to_path_buf(),
+            source,
+        },
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{load, profile_name, profiles, InputError, Source};
+    use crate::stand_in::temp_dir;
+    use std::path::PathBuf;
+
+    #[test]
+    fn dash_means_stdin() {
+        assert_eq!(Source::from_arg(Some(PathBuf::from("-"))), Source::Stdin);
+        assert_eq!(Source::from_arg(None), Source::Default);
+        assert_eq!(
+            Source::from_arg(Some(PathBuf::from("inputs/day01.txt"))),
+            Source::File(PathBuf::from("inputs/day01.txt"))
+        );
+    }
+
+    #[test]
+    fn load_from_a_file() {
+        let source = Source::File(PathBuf::from("inputs/day07.sample.txt"));
+        let data = load(7, &source).unwrap();
+        assert_eq!(data, include_str!("../inputs/day07.sample.txt"));
+    }
+
+    #[test]
+    fn missing_file_is_reported_with_its_path() {
+        let source = Source::File(PathBuf::from("inputs/day99.txt"));
+        let error = load(99, &source).unwrap_err();
+        assert!(matches!(error, InputError::Missing { .. }));
+        assert!(error.to_string().contains("inputs/day99.txt"));
+    }
+
+    #[test]
+    fn profiles_are_the_subdirectories() {
+        let dir = temp_dir("profiles");
+        for profile in ["bob", "alice", ".git"] {
+            std::fs::create_dir_all(dir.join(profile)).unwrap();
+        }
+        std::fs::write(dir.join("day01.txt"), "1\n").unwrap();
+        assert_eq!(profiles(&dir).unwrap(), vec!["alice", "bob"]);
+        std::fs::remove_dir_all(dir).unwrap();
+
+        assert!(profile_name("team-2.alice").is_ok());
+        assert!(profile_name("../alice").is_err());
+        assert!(profile_name("").is_err());
+        assert_eq!(Source::from_profile(None), Source::Default);
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+use crate::parse::ParseError;
+
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod compare;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod fetch;
+pub mod ffi;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod memory;
+pub mod parse;
+pub mod pool;
+pub mod report;
+pub mod runner;
+#[cfg(test)]
+mod samples;
+pub mod scaffold;
+pub mod server;
+pub mod simulation;
+#[cfg(test)]
+mod stand_in;
+pub mod submit;
+pub mod tui;
+pub mod usage;
+
+/// A puzzle of a given day: the raw input is parsed once into `Input`,
+/// then each part computes its answer from it.
+pub trait Solution {
+    const DAY: u8;
+    /// Version of the solution, to bump when it could give other answers: the answers
+    /// computed by the previous versions are no longer taken from the cache.
+    const VERSION: u32 = 1;
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError>;
+    fn part1(&self, input: &Self::Input) -> Self::Part1;
+    fn part2(&self, input: &Self::Input) -> Self::Part2;
+}
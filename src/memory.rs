@@ -0,0 +1,142 @@
+//! Heap memory used by the solutions, counted by a global allocator: how many allocations
+//! they make, how many bytes they allocate in all, and the most they hold at once. The
+//! memory resident for the whole process is in [`crate::usage`].
+//!
+//! The binary installs [`CountingAllocator`], which only counts once [`enable`] is called.
+//! The counts are kept per thread, so that the days and parts solved side by side are
+//! measured apart; memory freed by another thread than the one that allocated it is not
+//! followed.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+
+/// The system allocator, counting the allocations of each thread.
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Copy, Clone, Default)]
+struct Counts {
+    allocations: u64,
+    allocated: u64,
+    /// bytes allocated and not freed by this thread; it can be negative
+    live: i64,
+    /// most `live` bytes since the current measure started
+    peak: i64,
+}
+
+thread_local! {
+    // const, without destructor: reading it never allocates
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts {
+            allocations: 0,
+            allocated: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // the thread can be exiting
+    let _ = COUNTS.try_with(|counts| {
+        let mut current = counts.get();
+        if allocated > 0 {
+            current.allocations += 1;
+            current.allocated += allocated as u64;
+        }
+        current.live += allocated as i64 - freed as i64;
+        current.peak = current.peak.max(current.live);
+        counts.set(current);
+    });
+}
+
+// SAFETY: the memory comes from the system allocator, unchanged
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    /// Counted as a new allocation, as the memory may move.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Starts counting, when [`CountingAllocator`] is the global allocator.
+pub fn enable() {
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+/// Heap memory used by a step of a solution.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Memory {
+    pub allocations: u64,
+    #[serde(rename = "allocated_bytes")]
+    pub allocated: u64,
+    /// most bytes held at once beyond the ones held before the step
+    #[serde(rename = "heap_peak_bytes")]
+    pub heap_peak: u64,
+}
+
+/// Runs `f`, with the memory it used on this thread when counting is enabled.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<Memory>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+    let start = COUNTS.with(|counts| {
+        let start = counts.get();
+        counts.set(Counts {
+            peak: start.live,
+            ..start
+        });
+        start
+    });
+    let result = f();
+    let end = COUNTS.with(|counts| {
+        let end = counts.get();
+        // an enclosing measure keeps its own peak
+        counts.set(Counts {
+            peak: end.peak.max(start.peak),
+            ..end
+        });
+        end
+    });
+    let memory = Memory {
+        allocations: end.allocations - start.allocations,
+        allocated: end.allocated - start.allocated,
+        heap_peak: (end.peak - start.live).max(0) as u64,
+    };
+    (result, Some(memory))
+}
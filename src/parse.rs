@@ -0,0 +1,312 @@
+//! Errors located in the puzzle inputs, and the parsers of the shapes that recur in them:
+//! lines, groups of lines separated by blank lines, pairs like `x,y` or `2-4`, lists like
+//! `a -> b -> c`, numbers among fixed text (with [`Scanner`]), and character grids (with
+//! [`crate::grid::Grid::parse`]).
+//!
+//! The parsers of a line give an [`Expected`] located in the text they were given;
+//! [`Expected::within`] moves it to its place in the line, and [`ParseError::new`] to its
+//! place in the input.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A puzzle input that doesn't match the expected format.
+///
+/// Lines and columns start at 1; columns count bytes, which is the same as characters
+/// for the ASCII puzzle inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Locates an error found inside the line at `line_index` (0-based) of the input.
+    pub fn new(day: u8, line_index: usize, error: Expected) -> Self {
+        ParseError {
+            day,
+            line: line_index + 1,
+            column: error.column,
+            expected: error.expected,
+        }
+    }
+
+    /// The error message followed by the faulty line of `data`, with a caret under the column.
+    pub fn diagnostic(&self, data: &str) -> String {
+        let text = data.lines().nth(self.line - 1).unwrap_or_default();
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        format!(
+            "{self}\n{margin} |\n{number} | {text}\n{margin} | {:>column$}",
+            "^",
+            column = self.column
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {:02}, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// What a parser expected at a given column of the line it was reading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub column: usize,
+    pub expected: String,
+}
+
+impl Expected {
+    pub fn at(column: usize, expected: impl Into<String>) -> Self {
+        Expected {
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// Moves the error of a parser that only read `part` of `line` to its place in the line.
+    pub fn within(self, line: &str, part: &str) -> Self {
+        Expected {
+            column: column_of(line, part) + self.column - 1,
+            ..self
+        }
+    }
+}
+
+/// Column where `part`, a sub-slice of `line`, starts.
+pub fn column_of(line: &str, part: &str) -> usize {
+    part.as_ptr() as usize - line.as_ptr() as usize + 1
+}
+
+/// Parses `text`, a sub-slice of `line`, as a number.
+pub fn number<T: FromStr>(line: &str, text: &str) -> Result<T, Expected> {
+    text.parse::<T>()
+        .map_err(|_| Expected::at(column_of(line, text), "a number"))
+}
+
+/// Parses every line of `data` with `parse_line`, locating the errors in the input.
+pub fn lines<T>(
+    day: u8,
+    data: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, Expected>,
+) -> Result<Vec<T>, ParseError> {
+    data.lines()
+        .enumerate()
+        .map(|(idx, line)| parse_line(line).map_err(|error| ParseError::new(day, idx, error)))
+        .collect()
+}
+
+/// A line of the input, with its index (0-based).
+pub type Line<'a> = (usize, &'a str);
+
+/// Parses the groups of lines of `data` separated by blank lines with `parse_group`, which is
+/// given the lines of a group with their index.
+pub fn groups<'a, T>(
+    data: &'a str,
+    mut parse_group: impl FnMut(&[Line<'a>]) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut groups = vec![];
+    let mut group = vec![];
+    for (idx, line) in data.lines().enumerate() {
+        if !line.is_empty() {
+            group.push((idx, line));
+        } else if !group.is_empty() {
+            groups.push(parse_group(&group)?);
+            group.clear();
+        }
+    }
+    if !group.is_empty() {
+        groups.push(parse_group(&group)?);
+    }
+    Ok(groups)
+}
+
+/// Splits `text` around the first `separator`, like `x,y` or `2-4`; without it, `expected`
+/// is missing at the end of `text`.
+pub fn split_pair<'a>(
+    text: &'a str,
+    separator: &str,
+    expected: &str,
+) -> Result<(&'a str, &'a str), Expected> {
+    text.split_once(separator)
+        .ok_or_else(|| Expected::at(text.len() + 1, expected))
+}
+
+/// Parses the items of `text` separated by `separator`, like `a -> b -> c`. The errors of
+/// `item`, located in the item, are moved to their place in `text`.
+pub fn separated<'a, T>(
+    text: &'a str,
+    separator: &str,
+    mut item: impl FnMut(&'a str) -> Result<T, Expected>,
+) -> Result<Vec<T>, Expected> {
+    text.split(separator)
+        .map(|part| item(part).map_err(|error| error.within(text, part)))
+        .collect()
+}
+
+/// Reads a line from left to right, for the lines made of fixed text and values, like
+/// `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    line: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Scanner { line, rest: line }
+    }
+
+    /// Column of the next character to read.
+    pub fn column(&self) -> usize {
+        column_of(self.line, self.rest)
+    }
+
+    /// Reads `tag`, which must come next.
+    pub fn tag(&mut self, tag: &str) -> Result<(), Expected> {
+        match self.rest.strip_prefix(tag) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(Expected::at(self.column(), format!("`{tag}`"))),
+        }
+    }
+
+    /// Reads a number, with its sign if it has one.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, Expected> {
+        let sign = usize::from(self.rest.starts_with(['-', '+']));
+        let len = sign
+            + self.rest[sign..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(self.rest.len() - sign);
+        let (text, rest) = self.rest.split_at(len);
+        let value = number(self.line, text)?;
+        self.rest = rest;
+        Ok(value)
+    }
+
+    /// Reads what is left of the line.
+    pub fn rest(&mut self) -> &'a str {
+        std::mem::take(&mut self.rest)
+    }
+
+    /// Checks that the whole line was read.
+    pub fn end(&self) -> Result<(), Expected> {
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(Expected::at(self.column(), "the end of the line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{groups, lines, number, separated, split_pair, Expected, ParseError, Scanner};
+
+    #[test]
+    fn number_is_located_in_its_line() {
+        let line = "move x from 1 to 2";
+        let error = number::<usize>(line, &line[5..6]).unwrap_err();
+        assert_eq!(error, Expected::at(6, "a number"));
+    }
+
+    #[test]
+    fn within_shifts_the_column() {
+        let line = "498,4 -> 49a,6";
+        let error = Expected::at(3, "a number").within(line, &line[9..]);
+        assert_eq!(error.column, 12);
+    }
+
+    #[test]
+    fn lines_reports_the_faulty_line() {
+        let error = lines(1, "12\n34\nab\n", |line| number::<usize>(line, line)).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                day: 1,
+                line: 3,
+                column: 1,
+                expected: String::from("a number")
+            }
+        );
+    }
+
+    #[test]
+    fn diagnostic_points_at_the_column() {
+        let error = ParseError {
+            day: 9,
+            line: 2,
+            column: 3,
+            expected: String::from("a number"),
+        };
+        assert_eq!(
+            error.diagnostic("R 4\nU x\nL 3"),
+            "day 09, line 2, column 3: expected a number\n  |\n2 | U x\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn groups_are_separated_by_blank_lines() {
+        let data = "1\n2\n\n\n3\n\n4\n5\n";
+        let sums = groups(data, |group| {
+            let numbers = group.iter().map(|&(idx, line)| {
+                number::<usize>(line, line).map_err(|error| ParseError::new(1, idx, error))
+            });
+            numbers.sum::<Result<usize, ParseError>>()
+        });
+        assert_eq!(sums, Ok(vec![3, 3, 9]));
+
+        let indices = groups("a\n\nb\nc", |group| Ok(group.to_vec())).unwrap();
+        assert_eq!(indices, vec![vec![(0, "a")], vec![(2, "b"), (3, "c")]]);
+        assert_eq!(groups("", |_| Ok(())), Ok(vec![]));
+    }
+
+    #[test]
+    fn pairs_and_lists_locate_their_errors() {
+        assert_eq!(split_pair("2-4", "-", "a dash"), Ok(("2", "4")));
+        assert_eq!(
+            split_pair("24", "-", "a dash"),
+            Err(Expected::at(3, "a dash"))
+        );
+
+        let line = "498,4 -> 498,6 -> 49x,6";
+        let points = separated(line, " -> ", |part| {
+            let (x, y) = split_pair(part, ",", "a comma")?;
+            Ok((number::<u32>(part, x)?, number::<u32>(part, y)?))
+        });
+        assert_eq!(points, Err(Expected::at(19, "a number")));
+        assert_eq!(
+            separated("1, 2, 3", ", ", |part| number::<u8>(part, part)),
+            Ok(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn scanner_reads_fixed_text_and_numbers() {
+        let mut scanner = Scanner::new("x=-2, y=+15 and more");
+        assert_eq!(scanner.tag("x="), Ok(()));
+        assert_eq!(scanner.number::<i32>(), Ok(-2));
+        assert_eq!(scanner.tag(", y="), Ok(()));
+        assert_eq!(scanner.number::<i32>(), Ok(15));
+        assert_eq!(scanner.end(), Err(Expected::at(12, "the end of the line")));
+        assert_eq!(scanner.tag(":"), Err(Expected::at(12, "`:`")));
+        assert_eq!(scanner.rest(), " and more");
+        assert_eq!(scanner.end(), Ok(()));
+
+        let mut scanner = Scanner::new("x=-, y");
+        scanner.tag("x=").unwrap();
+        assert_eq!(scanner.number::<i32>(), Err(Expected::at(3, "a number")));
+        assert_eq!(scanner.column(), 3);
+    }
+}
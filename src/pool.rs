@@ -0,0 +1,73 @@
+//! A fixed number of threads taking tasks from a shared list, so that independent days can be
+//! solved side by side.
+
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// As many threads as the machine runs at once.
+pub fn default_threads() -> usize {
+    std::thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// `f` applied to every item by `threads` threads, the results in the order of the items
+/// whatever the order they were computed in.
+pub fn map<T, R, F>(items: &[T], threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads.clamp(1, items.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = vec![];
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(idx) else {
+                            break;
+                        };
+                        done.push((idx, f(item)));
+                    }
+                    done
+                })
+            })
+            .collect();
+        for worker in workers {
+            let done = worker
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+            for (idx, result) in done {
+                results[idx] = Some(result);
+            }
+        }
+    });
+    results.into_iter().map(Option::unwrap).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::map;
+    use std::time::Duration;
+
+    #[test]
+    fn results_in_the_order_of_the_items() {
+        // the first items take the longest, to be finished last
+        let items: Vec<u64> = (0..20).collect();
+        let squares = map(&items, 4, |&n| {
+            std::thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        });
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        assert_eq!(map(&items, 1, |&n| n + 1)[19], 20);
+        assert!(map(&[] as &[u8], 4, |&n| n).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "day 13")]
+    fn panics_reach_the_caller() {
+        map(&[12, 13, 14], 2, |&day| assert_ne!(day, 13, "day 13"));
+    }
+}
@@ -0,0 +1,106 @@
+//! Answers in JSON, for the tools that read them rather than people.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::answers;
+use crate::memory::Memory;
+use crate::runner::{Answer, Kind, Part};
+
+/// One answer of a day, as printed by `--format json`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    #[serde(with = "answers::part_number")]
+    pub part: Part,
+    #[serde(rename = "type")]
+    pub kind: Kind,
+    /// a JSON number, a string, or the lines of the art
+    pub answer: Value,
+    pub elapsed_ns: u64,
+    /// [`answers::input_hash`] of the input the answer was computed from
+    pub input: String,
+    /// only given when the answer comes from the cache
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub cached: bool,
+    /// `allocations`, `allocated_bytes` and `heap_peak_bytes`, when profiling the memory
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Memory>,
+}
+
+impl Record {
+    pub fn new(day: u8, input: &str, answer: &Answer) -> Self {
+        let kind = answer.kind();
+        let value = match kind {
+            // numbers that don't fit in 64 bits are kept as strings, to stay exact
+            Kind::Number => match (answer.value.parse::<i64>(), answer.value.parse::<u64>()) {
+                (Ok(n), _) => Value::from(n),
+                (_, Ok(n)) => Value::from(n),
+                _ => Value::from(answer.value.as_str()),
+            },
+            Kind::String => Value::from(answer.value.as_str()),
+            Kind::Art => Value::from_iter(answer.value.lines()),
+        };
+        Record {
+            day,
+            part: answer.part,
+            kind,
+            answer: value,
+            elapsed_ns: answer.elapsed.as_nanos() as u64,
+            input: String::from(input),
+            cached: answer.cached,
+            memory: answer.memory,
+        }
+    }
+}
+
+/// A day that couldn't be solved, as printed by `--format json`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Failure {
+    pub day: u8,
+    pub error: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::Record;
+    use crate::runner::{Answer, Part};
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn record(value: &str) -> serde_json::Value {
+        let answer = Answer {
+            part: Part::Two,
+            value: String::from(value),
+            elapsed: Duration::from_micros(15),
+            cached: false,
+            memory: None,
+        };
+        serde_json::to_value(Record::new(10, "0123456789abcdef", &answer)).unwrap()
+    }
+
+    #[test]
+    fn answers_keep_their_type() {
+        assert_eq!(
+            record("11557863040754"),
+            json!({
+                "day": 10,
+                "part": 2,
+                "type": "number",
+                "answer": 11557863040754u64,
+                "elapsed_ns": 15000,
+                "input": "0123456789abcdef"
+            })
+        );
+        assert_eq!(record("CMZ")["answer"], "CMZ");
+        assert_eq!(record("CMZ")["type"], "string");
+        assert_eq!(record("#..#\n####")["answer"], json!(["#..#", "####"]));
+        assert_eq!(record("#..#\n####")["type"], "art");
+    }
+
+    #[test]
+    fn numbers_too_big_for_json_stay_exact() {
+        let big = "123456789012345678901234567890";
+        assert_eq!(record(big)["answer"], big);
+    }
+}
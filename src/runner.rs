@@ -0,0 +1,458 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tracing::{debug, info_span};
+
+use crate::day01::Day01;
+use crate::day02::Day02;
+use crate::day03::Day03;
+use crate::day04::Day04;
+use crate::day05::Day05;
+use crate::day06::Day06;
+use crate::day07::Day07;
+use crate::day08::Day08;
+use crate::day09::Day09;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::day12::Day12;
+use crate::day13::Day13;
+use crate::day14::Day14;
+use crate::day15::Day15;
+use crate::input::{self, Source};
+use crate::memory::{self, Memory};
+use crate::parse::ParseError;
+use crate::Solution;
+
+/// Days that have a solution, in order.
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=15;
+
+/// Puzzle parameters by name, for the days whose samples use other values than the real
+/// puzzle.
+pub type Params = BTreeMap<String, isize>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration,
+    /// taken from the [`Cache`](crate::cache::Cache) rather than computed
+    pub cached: bool,
+    /// when profiling the memory
+    pub memory: Option<Memory>,
+}
+
+/// What an answer looks like, for the tools that read them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Number,
+    String,
+    /// letters drawn on several lines, like the screen of day 10
+    Art,
+}
+
+impl Answer {
+    pub fn kind(&self) -> Kind {
+        let digits = self.value.strip_prefix('-').unwrap_or(&self.value);
+        if self.value.contains('\n') {
+            Kind::Art
+        } else if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+            Kind::Number
+        } else {
+            Kind::String
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub parse_time: Duration,
+    /// when profiling the memory
+    pub parse_memory: Option<Memory>,
+    pub answers: Vec<Answer>,
+}
+
+impl Solved {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.answers.iter().map(|a| a.elapsed).sum::<Duration>()
+    }
+}
+
+/// Object-safe view of a [`Solution`], so that days can be picked at runtime.
+pub trait Solver: Send + Sync {
+    /// Parses `data` once and computes the requested parts, in the given order.
+    fn solve(&self, data: &str, parts: &[Part]) -> Result<Solved, ParseError>;
+
+    /// Like [`Solver::solve`], but each part is computed on its own thread once the input is
+    /// parsed. The answers are the same, in the same order.
+    fn solve_parallel(&self, data: &str, parts: &[Part]) -> Result<Solved, ParseError>;
+
+    /// [`Solution::VERSION`] of the day.
+    fn version(&self) -> u32;
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Send + Sync,
+    S::Input: Sync,
+{
+    fn solve(&self, data: &str, parts: &[Part]) -> Result<Solved, ParseError> {
+        let _span = info_span!("day", day = S::DAY).entered();
+        let (input, parse_time, parse_memory) = parse(self, data)?;
+        let answers = parts
+            .iter()
+            .map(|&part| answer(self, &input, part))
+            .collect();
+        Ok(Solved {
+            parse_time,
+            parse_memory,
+            answers,
+        })
+    }
+
+    fn solve_parallel(&self, data: &str, parts: &[Part]) -> Result<Solved, ParseError> {
+        let span = info_span!("day", day = S::DAY);
+        let (input, parse_time, parse_memory) = span.in_scope(|| parse(self, data))?;
+        let answers = std::thread::scope(|scope| {
+            let threads: Vec<_> = parts
+                .iter()
+                .map(|&part| {
+                    let (span, input) = (&span, &input);
+                    scope.spawn(move || span.in_scope(|| answer(self, input, part)))
+                })
+                .collect();
+            threads
+                .into_iter()
+                .map(|thread| {
+                    thread
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        });
+        Ok(Solved {
+            parse_time,
+            parse_memory,
+            answers,
+        })
+    }
+
+    fn version(&self) -> u32 {
+        S::VERSION
+    }
+}
+
+fn parse<S: Solution>(
+    solution: &S,
+    data: &str,
+) -> Result<(S::Input, Duration, Option<Memory>), ParseError> {
+    let start = Instant::now();
+    let (input, memory) = memory::measure(|| solution.parse(data));
+    let parse_time = start.elapsed();
+    debug!(?parse_time, ?memory, "input parsed");
+    Ok((input?, parse_time, memory))
+}
+
+fn answer<S: Solution>(solution: &S, input: &S::Input, part: Part) -> Answer {
+    let _span = info_span!("part", part = %part).entered();
+    let (value, elapsed, memory) = match part {
+        Part::One => measure_part(|| solution.part1(input)),
+        Part::Two => measure_part(|| solution.part2(input)),
+    };
+    debug!(?elapsed, ?memory, "solved");
+    Answer {
+        part,
+        value,
+        elapsed,
+        cached: false,
+        memory,
+    }
+}
+
+/// Times a part and measures its memory, then formats its answer: the formatting is left
+/// out.
+fn measure_part<T: Display>(part: impl FnOnce() -> T) -> (String, Duration, Option<Memory>) {
+    let start = Instant::now();
+    let (value, memory) = memory::measure(part);
+    let elapsed = start.elapsed();
+    (value.to_string(), elapsed, memory)
+}
+
+/// The solver of the given day, with the parameters of the real puzzle.
+pub fn solver(day: u8) -> Option<Box<dyn Solver>> {
+    let solver: Box<dyn Solver> = match day {
+        1 => Box::new(Day01),
+        2 => Box::new(Day02),
+        3 => Box::new(Day03),
+        4 => Box::new(Day04),
+        5 => Box::new(Day05),
+        6 => Box::new(Day06),
+        7 => Box::new(Day07),
+        8 => Box::new(Day08),
+        9 => Box::new(Day09),
+        10 => Box::new(Day10),
+        11 => Box::new(Day11),
+        12 => Box::new(Day12),
+        13 => Box::new(Day13),
+        14 => Box::new(Day14),
+        15 => Box::new(Day15::default()),
+        _ => return None,
+    };
+    Some(solver)
+}
+
+/// The solver of the given day, with the parameters found in `params` instead of the ones
+/// of the real puzzle.
+pub fn solver_with(day: u8, params: &Params) -> Result<Box<dyn Solver>, String> {
+    let mut params = params.clone();
+    let mut param = |name: &str, default: isize| params.remove(name).unwrap_or(default);
+    let solver: Box<dyn Solver> = match day {
+        15 => {
+            let default = Day15::default();
+            Box::new(Day15 {
+                row: param("row", default.row),
+                search_limit: param("search_limit", default.search_limit),
+            })
+        }
+        _ => solver(day).ok_or_else(|| format!("no solution for day {day}"))?,
+    };
+    match params.keys().next() {
+        Some(name) => Err(format!("day {day} has no parameter {name}")),
+        None => Ok(solver),
+    }
+}
+
+/// Parameters written like a URL query, `row=10&search_limit=20`.
+pub fn parse_params(text: &str) -> Result<Params, String> {
+    text.split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (name, value) = param.split_once('=').unwrap_or((param, ""));
+            match value.parse() {
+                Ok(value) => Ok((String::from(name), value)),
+                Err(_) => Err(format!("the parameter {name} should be a number")),
+            }
+        })
+        .collect()
+}
+
+/// Prints an answer, the multi-line ones below their heading.
+pub fn print_answer(day: u8, answer: &Answer) {
+    if answer.value.contains('\n') {
+        println!("Day {day:02} part {}:\n{}", answer.part, answer.value);
+    } else {
+        println!("Day {day:02} part {}: {}", answer.part, answer.value);
+    }
+}
+
+/// The `main` of the `dayNN` binaries: prints both answers of `day` on `inputs/dayNN.txt`,
+/// like `aoc run <day>`.
+pub fn run_day(day: u8) -> ExitCode {
+    let Some(solver) = solver(day) else {
+        eprintln!("no solution for day {day}");
+        return ExitCode::from(2);
+    };
+    let data = match input::load(day, &Source::Default) {
+        Ok(data) => data,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match solver.solve(&data, &Part::BOTH) {
+        Ok(solved) => {
+            for answer in &solved.answers {
+                print_answer(day, answer);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {}", error.diagnostic(&data));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The message of a panic caught while solving.
+pub fn panic_message(panic: &(dyn Any + Send)) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|message| String::from(*message))
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_params, solver, solver_with, Answer, Kind, Params, Part, Solved, DAYS};
+    use crate::generate::generate;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use std::path::Path;
+    use std::time::Duration;
+
+    /// Inputs that crashed the fuzz targets (see the README).
+    #[test]
+    fn parse_fuzz_regressions() {
+        for day in DAYS {
+            let dir = Path::new("fuzz/regressions").join(format!("day{day:02}"));
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+            for entry in entries {
+                let data = std::fs::read(entry.unwrap().path()).unwrap();
+                let _ = solver(day)
+                    .unwrap()
+                    .solve(&String::from_utf8_lossy(&data), &[]);
+            }
+        }
+    }
+
+    // Solving no part only parses: whatever the input, the parsers must give an error
+    // rather than panic.
+    proptest! {
+        #[test]
+        fn parse_arbitrary_bytes(day in DAYS, bytes in vec(any::<u8>(), 0..256)) {
+            let _ = solver(day).unwrap().solve(&String::from_utf8_lossy(&bytes), &[]);
+        }
+
+        #[test]
+        fn parse_arbitrary_text(day in DAYS, data in "[ -~\n]{0,256}") {
+            let _ = solver(day).unwrap().solve(&data, &[]);
+        }
+
+        #[test]
+        fn parse_corrupted_inputs(
+            day in DAYS,
+            seed in any::<u64>(),
+            position in any::<prop::sample::Index>(),
+            byte in b' '..=b'~',
+            cut in any::<bool>(),
+        ) {
+            let mut data = generate(day, 5, seed).unwrap().into_bytes();
+            let idx = position.index(data.len());
+            if cut {
+                data.truncate(idx);
+            } else {
+                data[idx] = byte;
+            }
+            let _ = solver(day).unwrap().solve(&String::from_utf8(data).unwrap(), &[]);
+        }
+    }
+
+    #[test]
+    fn every_day_has_a_solver() {
+        for day in DAYS {
+            assert!(solver(day).is_some(), "no solver for day {day}");
+        }
+        assert!(solver(0).is_none());
+        assert!(solver(DAYS.end() + 1).is_none());
+    }
+
+    #[test]
+    fn solve_keeps_the_order_of_parts() {
+        let data = include_str!("../inputs/day07.sample.txt");
+        let solved = solver(7)
+            .unwrap()
+            .solve(data, &[Part::Two, Part::One])
+            .unwrap();
+        let values: Vec<(Part, &str)> = solved
+            .answers
+            .iter()
+            .map(|a| (a.part, a.value.as_str()))
+            .collect();
+        assert_eq!(values, vec![(Part::Two, "24933642"), (Part::One, "95437")]);
+    }
+
+    #[test]
+    fn parts_in_parallel_give_the_same_answers() {
+        let samples = [
+            (7, include_str!("../inputs/day07.sample.txt")),
+            (10, include_str!("../inputs/day10.sample.txt")),
+            (11, include_str!("../inputs/day11.sample.txt")),
+            (13, include_str!("../inputs/day13.sample.txt")),
+        ];
+        let values = |solved: Solved| -> Vec<(Part, String)> {
+            solved
+                .answers
+                .into_iter()
+                .map(|a| (a.part, a.value))
+                .collect()
+        };
+        for (day, data) in samples {
+            let solver = solver(day).unwrap();
+            let parts = [Part::Two, Part::One];
+            assert_eq!(
+                values(solver.solve_parallel(data, &parts).unwrap()),
+                values(solver.solve(data, &parts).unwrap()),
+                "day {day}"
+            );
+        }
+    }
+
+    #[test]
+    fn parameters_replace_the_puzzle_ones() {
+        let data = include_str!("../inputs/day15.sample.txt");
+        let params = Params::from([(String::from("row"), 10)]);
+        let solved = solver_with(15, &params)
+            .unwrap()
+            .solve(data, &[Part::One])
+            .unwrap();
+        assert_eq!(solved.answers[0].value, "26");
+
+        let params = Params::from([(String::from("rows"), 10)]);
+        assert!(solver_with(15, &params).is_err());
+        assert!(solver_with(1, &params).is_err());
+
+        let params = Params::from([
+            (String::from("row"), 10),
+            (String::from("search_limit"), -2),
+        ]);
+        assert_eq!(parse_params("row=10&search_limit=-2"), Ok(params));
+        assert_eq!(parse_params(""), Ok(Params::new()));
+        assert!(parse_params("row=ten").is_err());
+        assert!(parse_params("row").is_err());
+    }
+
+    #[test]
+    fn kind_of_the_answers() {
+        let kind = |value: &str| {
+            Answer {
+                part: Part::One,
+                value: String::from(value),
+                elapsed: Duration::ZERO,
+                cached: false,
+                memory: None,
+            }
+            .kind()
+        };
+        assert_eq!(kind("11557863040754"), Kind::Number);
+        assert_eq!(kind("-12"), Kind::Number);
+        assert_eq!(kind("CMZ"), Kind::String);
+        assert_eq!(kind("-"), Kind::String);
+        assert_eq!(kind("##..\n#..#"), Kind::Art);
+    }
+}
@@ -0,0 +1,100 @@
+//! The sample inputs of the puzzles, with the answers given in their statements.
+//!
+//! Every sample listed in `inputs/samples.json` is checked by the same test, so adding a
+//! sample only takes a new entry in the manifest.
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::answers;
+use crate::runner::{solver_with, Params, Part};
+
+const INPUTS_DIR: &str = "inputs";
+const MANIFEST: &str = "inputs/samples.json";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Sample {
+    pub(crate) day: u8,
+    /// relative to the `inputs` directory
+    pub(crate) file: PathBuf,
+    /// parameters of the sample that differ from the real puzzle
+    #[serde(default)]
+    pub(crate) params: Params,
+    #[serde(default, deserialize_with = "answers::multiline::optional")]
+    pub(crate) part1: Option<String>,
+    #[serde(default, deserialize_with = "answers::multiline::optional")]
+    pub(crate) part2: Option<String>,
+}
+
+impl Sample {
+    /// Describes every answer that differs from the expected one.
+    fn check(&self) -> Vec<String> {
+        let name = format!("day {:02} on {}", self.day, self.file.display());
+        let expected: Vec<(Part, &str)> = [(Part::One, &self.part1), (Part::Two, &self.part2)]
+            .into_iter()
+            .filter_map(|(part, answer)| answer.as_deref().map(|answer| (part, answer)))
+            .collect();
+        if expected.is_empty() {
+            return vec![format!("{name}: no expected answer")];
+        }
+
+        let solver = match solver_with(self.day, &self.params) {
+            Ok(solver) => solver,
+            Err(error) => return vec![format!("{name}: {error}")],
+        };
+        let data = match std::fs::read_to_string(Path::new(INPUTS_DIR).join(&self.file)) {
+            Ok(data) => data,
+            Err(error) => return vec![format!("{name}: {error}")],
+        };
+        let parts: Vec<Part> = expected.iter().map(|&(part, _)| part).collect();
+        let solved = match solver.solve(&data, &parts) {
+            Ok(solved) => solved,
+            Err(error) => return vec![format!("{name}: {error}")],
+        };
+        expected
+            .iter()
+            .zip(solved.answers)
+            .filter(|(&(_, expected), answer)| expected != answer.value)
+            .map(|(&(part, expected), answer)| {
+                format!(
+                    "{name}, part {part}: expected\n{expected}\nbut got\n{}",
+                    answer.value
+                )
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn manifest() -> Vec<Sample> {
+    let json = std::fs::read_to_string(MANIFEST).unwrap();
+    serde_json::from_str(&json).unwrap_or_else(|error| panic!("invalid {MANIFEST}: {error}"))
+}
+
+mod test {
+    use super::{manifest, INPUTS_DIR};
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    #[test]
+    fn every_sample_gives_its_expected_answers() {
+        let failures: Vec<String> = manifest().iter().flat_map(|s| s.check()).collect();
+        assert!(failures.is_empty(), "\n{}", failures.join("\n\n"));
+    }
+
+    #[test]
+    fn every_sample_file_is_in_the_manifest() {
+        let listed: HashSet<PathBuf> = manifest().into_iter().map(|s| s.file).collect();
+        for entry in std::fs::read_dir(INPUTS_DIR).unwrap() {
+            let file = PathBuf::from(entry.unwrap().file_name());
+            if file.to_string_lossy().contains(".sample") {
+                assert!(
+                    listed.contains(&file),
+                    "{} is not in the manifest",
+                    file.display()
+                );
+            }
+        }
+    }
+}
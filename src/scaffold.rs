@@ -0,0 +1,424 @@
+//! The files of a new day, and its registration everywhere the days are listed.
+//!
+//! Every piece is a placeholder that agrees with the empty sample, so that the tests pass
+//! until the sample of the puzzle statement is pasted in.
+
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+const MODULE: &str = r#"use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day__NN__;
+
+impl Solution for Day__NN__ {
+    const DAY: u8 = __N__;
+    /// the lines of the input, until the puzzle tells what they are
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, data: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, data, |line| Ok(String::from(line)))
+    }
+
+    fn part1(&self, lines: &Self::Input) -> usize {
+        lines.len()
+    }
+
+    fn part2(&self, lines: &Self::Input) -> usize {
+        lines.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day__NN__;
+    use crate::Solution;
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day__NN__
+            .parse(include_str!("../inputs/day__NN__.sample.txt"))
+            .unwrap();
+        // placeholder: the answer of the puzzle statement for the sample
+        assert_eq!(Day__NN__.part1(&input), 0);
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day__NN__
+            .parse(include_str!("../inputs/day__NN__.sample.txt"))
+            .unwrap();
+        // placeholder: the answer of the puzzle statement for the sample
+        assert_eq!(Day__NN__.part2(&input), 0);
+    }
+}
+"#;
+
+const GENERATOR: &str = r#"/// `size` numbers, until the format of the input is known.
+fn day__NN__(size: usize, rng: &mut StdRng) -> String {
+    (0..size).fold(String::new(), |mut input, _| {
+        let _ = writeln!(input, "{}", rng.gen_range(0..1000));
+        input
+    })
+}
+
+"#;
+
+const BIN: &str = r#"use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_2022_rust::runner::run_day(__N__)
+}
+"#;
+
+const FUZZ_TARGET: &str = r#"#![no_main]
+
+use aoc_2022_rust::day__NN__::Day__NN__;
+use aoc_2022_rust::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = Day__NN__.parse(data);
+});
+"#;
+
+const FUZZ_BIN: &str = r#"
+[[bin]]
+name = "day__NN__"
+path = "fuzz_targets/day__NN__.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// the days are a range: the new one must follow the last one
+    NotNext {
+        day: u8,
+        next: u8,
+    },
+    Exists {
+        path: PathBuf,
+    },
+    /// a file listing the days doesn't look as expected
+    Unexpected {
+        path: PathBuf,
+        missing: &'static str,
+    },
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::NotNext { day, next } => {
+                write!(f, "can't add day {day}: the next day is {next}")
+            }
+            ScaffoldError::Exists { path } => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Unexpected { path, missing } => {
+                write!(f, "can't register the day in {}: {missing}", path.display())
+            }
+            ScaffoldError::Io { path, source } => {
+                write!(f, "can't access {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// What a new day changes in the repository.
+#[derive(Debug, Default)]
+pub struct Changes {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+/// Adds `day` to the repository at `root`: creates its module, its binary, its input and
+/// sample files and its fuzz target, and registers it with the runner, the generators and the samples.
+///
+/// Every file is first written beside its destination, and only moved in place once all of
+/// them could be written: a failure leaves the repository as it was.
+pub fn new_day(root: &Path, day: u8) -> Result<Changes, ScaffoldError> {
+    let fill = |template: &str| {
+        template
+            .replace("__NN__", &format!("{day:02}"))
+            .replace("__N__", &day.to_string())
+    };
+    let read = |path: &str| {
+        let path = root.join(path);
+        std::fs::read_to_string(&path).map_err(|source| ScaffoldError::Io { path, source })
+    };
+    let unexpected = |path: &str, missing| ScaffoldError::Unexpected {
+        path: root.join(path),
+        missing,
+    };
+
+    let runner = read("src/runner.rs")?;
+    let next = last_day(&runner).ok_or_else(|| unexpected("src/runner.rs", "no `DAYS`"))? + 1;
+    if day != next {
+        return Err(ScaffoldError::NotNext { day, next });
+    }
+    let module = format!("src/day{day:02}.rs");
+    if root.join(&module).exists() {
+        return Err(ScaffoldError::Exists {
+            path: root.join(module),
+        });
+    }
+
+    let updates = [
+        (
+            "src/lib.rs",
+            register_module(&read("src/lib.rs")?, day)
+                .ok_or_else(|| unexpected("src/lib.rs", "no `pub mod dayNN;`"))?,
+        ),
+        (
+            "src/runner.rs",
+            register_solver(&runner, day).ok_or_else(|| {
+                unexpected(
+                    "src/runner.rs",
+                    "no `use crate::dayNN` or `N => Box::new(DayNN)`",
+                )
+            })?,
+        ),
+        (
+            "src/generate.rs",
+            register_generator(&read("src/generate.rs")?, day, &fill(GENERATOR)).ok_or_else(
+                || {
+                    unexpected(
+                        "src/generate.rs",
+                        "no `N => dayNN(size, rng),` or test module",
+                    )
+                },
+            )?,
+        ),
+        (
+            "inputs/samples.json",
+            register_sample(&read("inputs/samples.json")?, day)
+                .ok_or_else(|| unexpected("inputs/samples.json", "no closing `]`"))?,
+        ),
+        (
+            "fuzz/Cargo.toml",
+            read("fuzz/Cargo.toml")? + &fill(FUZZ_BIN),
+        ),
+    ];
+    let created = [
+        (module, fill(MODULE)),
+        (format!("src/bin/day{day:02}.rs"), fill(BIN)),
+        (format!("inputs/day{day:02}.txt"), String::new()),
+        (format!("inputs/day{day:02}.sample.txt"), String::new()),
+        (
+            format!("fuzz/fuzz_targets/day{day:02}.rs"),
+            fill(FUZZ_TARGET),
+        ),
+    ];
+
+    let files: Vec<(PathBuf, String, bool)> = created
+        .into_iter()
+        .map(|(path, content)| (root.join(path), content, true))
+        .filter(|(path, _, _)| !path.exists())
+        .chain(
+            updates
+                .into_iter()
+                .map(|(path, content)| (root.join(path), content, false)),
+        )
+        .collect();
+    let mut staged = vec![];
+    for (path, content, _) in &files {
+        let new = staged_path(path);
+        if let Err(error) = write(&new, content) {
+            for new in staged {
+                let _ = std::fs::remove_file(new);
+            }
+            return Err(error);
+        }
+        staged.push(new);
+    }
+
+    let mut changes = Changes::default();
+    for ((path, _, is_created), new) in files.into_iter().zip(staged) {
+        std::fs::rename(&new, &path).map_err(|source| ScaffoldError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        if is_created {
+            changes.created.push(path);
+        } else {
+            changes.updated.push(path);
+        }
+    }
+    Ok(changes)
+}
+
+/// Where the new content of `path` waits for every other file to be written.
+fn staged_path(path: &Path) -> PathBuf {
+    let mut staged = path.as_os_str().to_owned();
+    staged.push(".new");
+    PathBuf::from(staged)
+}
+
+fn write(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+    std::fs::write(path, content).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The end of the `DAYS` range.
+fn last_day(runner: &str) -> Option<u8> {
+    let days = Regex::new(r"pub const DAYS: .* = 1..=(\d+);").unwrap();
+    days.captures(runner)?[1].parse().ok()
+}
+
+/// Inserts `line` after the last line matching `after`, with the same indentation.
+fn insert_after_last(content: &str, after: &Regex, line: &str) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let idx = lines.iter().rposition(|l| after.is_match(l))?;
+    let indent = &lines[idx][..lines[idx].len() - lines[idx].trim_start().len()];
+    let mut result: Vec<String> = lines.iter().map(|l| String::from(*l)).collect();
+    result.insert(idx + 1, format!("{indent}{line}"));
+    Some(result.join("\n") + "\n")
+}
+
+fn register_module(lib: &str, day: u8) -> Option<String> {
+    let module = Regex::new(r"^pub mod day\d+;").unwrap();
+    insert_after_last(lib, &module, &format!("pub mod day{day:02};"))
+}
+
+fn register_solver(runner: &str, day: u8) -> Option<String> {
+    let import = Regex::new(r"^use crate::day\d+::").unwrap();
+    let solver = Regex::new(r"^\s+\d+ => Box::new\(Day\d+").unwrap();
+    let runner = insert_after_last(
+        runner,
+        &import,
+        &format!("use crate::day{day:02}::Day{day:02};"),
+    )?;
+    let runner = insert_after_last(
+        &runner,
+        &solver,
+        &format!("{day} => Box::new(Day{day:02}),"),
+    )?;
+    let days = format!("= 1..={};", day - 1);
+    runner
+        .contains(&days)
+        .then(|| runner.replacen(&days, &format!("= 1..={day};"), 1))
+}
+
+fn register_generator(generate: &str, day: u8, generator: &str) -> Option<String> {
+    let arm = Regex::new(r"^\s+\d+ => day\d+\(size, rng\),").unwrap();
+    let generate = insert_after_last(generate, &arm, &format!("{day} => day{day:02}(size, rng),"))?;
+    let tests = generate.find("#[cfg(test)]")?;
+    Some(format!(
+        "{}{generator}{}",
+        &generate[..tests],
+        &generate[tests..]
+    ))
+}
+
+fn register_sample(manifest: &str, day: u8) -> Option<String> {
+    let end = manifest.rfind('}')? + 1;
+    manifest.get(end..)?.trim().starts_with(']').then(|| {
+        format!(
+            "{},\n  {{ \"day\": {day}, \"file\": \"day{day:02}.sample.txt\", \"part1\": \"0\", \
+             \"part2\": \"0\" }}{}",
+            &manifest[..end],
+            &manifest[end..]
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        last_day, new_day, register_generator, register_module, register_sample, register_solver,
+        ScaffoldError,
+    };
+    use crate::runner::DAYS;
+    use crate::stand_in::temp_dir;
+
+    #[test]
+    fn register_in_the_files_of_the_repository() {
+        let (last, next) = (*DAYS.end(), DAYS.end() + 1);
+        let lib = register_module(include_str!("lib.rs"), next).unwrap();
+        assert!(lib.contains(&format!("pub mod day{last:02};\npub mod day{next:02};\n")));
+
+        let runner = register_solver(include_str!("runner.rs"), next).unwrap();
+        assert!(runner.contains(&format!(
+            "use crate::day{last:02}::Day{last:02};\nuse crate::day{next:02}::Day{next:02};\n"
+        )));
+        assert!(runner.contains(&format!(
+            "        {next} => Box::new(Day{next:02}),\n        _ => return None,"
+        )));
+        assert_eq!(last_day(&runner), Some(next));
+
+        let generator = format!("fn day{next:02}() {{}}\n\n");
+        let generate = register_generator(include_str!("generate.rs"), next, &generator).unwrap();
+        assert!(generate.contains(&format!(
+            "        {next} => day{next:02}(size, rng),\n        _ => return None,"
+        )));
+        assert!(generate.contains(&format!("{generator}#[cfg(test)]")));
+
+        let samples = register_sample(include_str!("../inputs/samples.json"), next).unwrap();
+        let manifest: serde_json::Value = serde_json::from_str(&samples).unwrap();
+        let added = manifest.as_array().unwrap().last().unwrap();
+        assert_eq!(added["file"], format!("day{next:02}.sample.txt"));
+    }
+
+    #[test]
+    fn create_the_next_day_only() {
+        let next = DAYS.end() + 1;
+        let root = temp_dir("scaffold");
+        for file in [
+            "src/lib.rs",
+            "src/runner.rs",
+            "src/generate.rs",
+            "inputs/samples.json",
+            "fuzz/Cargo.toml",
+        ] {
+            std::fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            std::fs::copy(file, root.join(file)).unwrap();
+        }
+        std::fs::create_dir_all(root.join("src/bin")).unwrap();
+
+        // without a directory for the fuzz target, nothing is written
+        assert!(matches!(
+            new_day(&root, next),
+            Err(ScaffoldError::Io { path, .. }) if path.ends_with(format!("day{next:02}.rs.new"))
+        ));
+        assert!(!root.join(format!("src/day{next:02}.rs")).exists());
+        assert!(!root.join(format!("src/day{next:02}.rs.new")).exists());
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+            std::fs::read_to_string("src/lib.rs").unwrap()
+        );
+        std::fs::create_dir_all(root.join("fuzz/fuzz_targets")).unwrap();
+
+        assert!(matches!(
+            new_day(&root, next + 2),
+            Err(ScaffoldError::NotNext { next: n, .. }) if n == next
+        ));
+        let changes = new_day(&root, next).unwrap();
+        assert_eq!(changes.created.len(), 5);
+        assert_eq!(changes.updated.len(), 5);
+        let module = std::fs::read_to_string(root.join(format!("src/day{next:02}.rs"))).unwrap();
+        assert!(module.contains(&format!("const DAY: u8 = {next};")));
+        assert!(module.contains(&format!(
+            "include_str!(\"../inputs/day{next:02}.sample.txt\")"
+        )));
+        let sample = root.join(format!("inputs/day{next:02}.sample.txt"));
+        assert_eq!(std::fs::read_to_string(sample).unwrap(), "");
+        assert!(matches!(
+            new_day(&root, next),
+            Err(ScaffoldError::NotNext { next: n, .. }) if n == next + 1
+        ));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
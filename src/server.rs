@@ -0,0 +1,394 @@
+//! The solutions as a local HTTP service, for the tools that would rather not run `aoc`:
+//! `GET /days` lists the days and their parts, and `POST /days/{n}/parts/{p}` solves a part on
+//! the input sent as the body.
+//!
+//! A solution can't be stopped once started: past the time limit the client gets an error,
+//! while the solution goes on in the background, counted among the solves running until it
+//! ends. The service refuses new solves while too many are running.
+
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response};
+use tracing::{info, warn};
+
+use crate::answers;
+use crate::pool;
+use crate::report::Record;
+use crate::runner::{parse_params, solver_with, Params, Part, Solved, DAYS};
+
+pub const DEFAULT_ADDR: &str = "127.0.0.1:8022";
+
+/// Largest input accepted, in bytes: the real inputs are under 30 KB.
+pub const DEFAULT_MAX_INPUT: usize = 1 << 20;
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Checks a time limit given in seconds, for the command line: a positive number that a
+/// [`Duration`] can hold.
+pub fn timeout_secs(text: &str) -> Result<f64, String> {
+    let invalid = || format!("`{text}` is not a positive number of seconds");
+    let secs: f64 = text.parse().map_err(|_| invalid())?;
+    match Duration::try_from_secs_f64(secs) {
+        Ok(timeout) if !timeout.is_zero() => Ok(secs),
+        _ => Err(invalid()),
+    }
+}
+
+/// Serves the solutions until the process ends.
+pub struct Server {
+    http: tiny_http::Server,
+    limits: Limits,
+}
+
+#[derive(Debug, Clone)]
+struct Limits {
+    max_input: usize,
+    timeout: Duration,
+    max_running: usize,
+    /// solves running, including the ones past the time limit
+    running: Arc<AtomicUsize>,
+}
+
+impl Server {
+    /// Listens on `addr`, like `127.0.0.1:8022` (port 0 picks a free one).
+    pub fn bind(addr: &str) -> std::io::Result<Self> {
+        let http = tiny_http::Server::http(addr).map_err(std::io::Error::other)?;
+        Ok(Server {
+            http,
+            limits: Limits {
+                max_input: DEFAULT_MAX_INPUT,
+                timeout: DEFAULT_TIMEOUT,
+                max_running: pool::default_threads(),
+                running: Arc::new(AtomicUsize::new(0)),
+            },
+        })
+    }
+
+    pub fn max_input(mut self, bytes: usize) -> Self {
+        self.limits.max_input = bytes;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.limits.timeout = timeout;
+        self
+    }
+
+    /// Most solves running at once, the ones past the time limit included.
+    pub fn max_running(mut self, solves: usize) -> Self {
+        self.limits.max_running = solves;
+        self
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.http
+            .server_addr()
+            .to_ip()
+            .expect("listening on an IP address")
+    }
+
+    /// Answers the requests, each on its own thread.
+    pub fn serve(self) {
+        for request in self.http.incoming_requests() {
+            let limits = self.limits.clone();
+            std::thread::spawn(move || handle(request, &limits));
+        }
+    }
+}
+
+/// What a request gets instead of an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ApiError {
+    NotFound(String),
+    MethodNotAllowed,
+    BadRequest(String),
+    TooLarge {
+        max: usize,
+    },
+    /// the input can't be parsed, with the diagnostic
+    Invalid(String),
+    Busy,
+    Timeout(Duration),
+    Panicked,
+}
+
+impl ApiError {
+    fn status(&self) -> u16 {
+        match self {
+            ApiError::NotFound(_) => 404,
+            ApiError::MethodNotAllowed => 405,
+            ApiError::BadRequest(_) => 400,
+            ApiError::TooLarge { .. } => 413,
+            ApiError::Invalid(_) => 422,
+            ApiError::Panicked => 500,
+            ApiError::Busy | ApiError::Timeout(_) => 503,
+        }
+    }
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiError::NotFound(message) | ApiError::BadRequest(message) => {
+                write!(f, "{message}")
+            }
+            ApiError::MethodNotAllowed => write!(f, "method not allowed"),
+            ApiError::TooLarge { max } => write!(f, "input larger than {max} bytes"),
+            ApiError::Invalid(diagnostic) => write!(f, "{diagnostic}"),
+            ApiError::Busy => write!(f, "too many solves running, try again later"),
+            ApiError::Timeout(timeout) => write!(f, "no answer within {timeout:?}"),
+            ApiError::Panicked => write!(f, "the solution panicked on this input"),
+        }
+    }
+}
+
+/// An answer, as printed by `--format json`, with the time taken to parse the input.
+#[derive(Debug, Clone, Serialize)]
+struct Reply {
+    #[serde(flatten)]
+    record: Record,
+    parse_ns: u64,
+}
+
+fn handle(mut request: Request, limits: &Limits) {
+    let start = Instant::now();
+    let (status, body) = match route(&mut request, limits) {
+        Ok(body) => (200, body),
+        Err(error) => (error.status(), json!({ "error": error.to_string() })),
+    };
+    info!(
+        method = %request.method(),
+        url = request.url(),
+        status,
+        elapsed = ?start.elapsed(),
+        "request"
+    );
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+    if let Err(error) = request.respond(response) {
+        warn!(%error, "cannot send the response");
+    }
+}
+
+fn route(request: &mut Request, limits: &Limits) -> Result<Value, ApiError> {
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match (request.method(), segments.as_slice()) {
+        (Method::Get, ["days"]) => Ok(days()),
+        (Method::Post, ["days", day, "parts", part]) => {
+            let day = day
+                .parse::<u8>()
+                .ok()
+                .filter(|day| DAYS.contains(day))
+                .ok_or_else(|| ApiError::NotFound(format!("no solution for day {day}")))?;
+            let part = match *part {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(ApiError::NotFound(format!("no part {part}"))),
+            };
+            let params = parse_params(query).map_err(ApiError::BadRequest)?;
+            let data = body(request, limits.max_input)?;
+            solve(day, part, params, data, limits)
+        }
+        (_, ["days"]) | (_, ["days", _, "parts", _]) => Err(ApiError::MethodNotAllowed),
+        _ => Err(ApiError::NotFound(format!("nothing at {path}"))),
+    }
+}
+
+/// The days and their parts: `[{"day": 1, "parts": [1, 2]}, ...]`.
+fn days() -> Value {
+    DAYS.map(|day| json!({ "day": day, "parts": [1, 2] }))
+        .collect()
+}
+
+fn body(request: &mut Request, max_input: usize) -> Result<String, ApiError> {
+    let too_large = ApiError::TooLarge { max: max_input };
+    if request.body_length().is_some_and(|len| len > max_input) {
+        return Err(too_large);
+    }
+    let mut bytes = vec![];
+    request
+        .as_reader()
+        .take(max_input as u64 + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|error| ApiError::BadRequest(format!("cannot read the input: {error}")))?;
+    if bytes.len() > max_input {
+        return Err(too_large);
+    }
+    String::from_utf8(bytes).map_err(|_| ApiError::BadRequest(String::from("input not in UTF-8")))
+}
+
+/// A solve counted among the running ones until dropped.
+struct Running(Arc<AtomicUsize>);
+
+impl Running {
+    fn start(limits: &Limits) -> Option<Self> {
+        limits
+            .running
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |running| {
+                (running < limits.max_running).then_some(running + 1)
+            })
+            .ok()
+            .map(|_| Running(Arc::clone(&limits.running)))
+    }
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn solve(
+    day: u8,
+    part: Part,
+    params: Params,
+    data: String,
+    limits: &Limits,
+) -> Result<Value, ApiError> {
+    let solver = solver_with(day, &params).map_err(ApiError::BadRequest)?;
+    let input = answers::input_hash(&data);
+    let running = Running::start(limits).ok_or(ApiError::Busy)?;
+    let (sender, receiver) = mpsc::channel::<Result<Solved, String>>();
+    std::thread::spawn(move || {
+        let solved = solver
+            .solve(&data, &[part])
+            .map_err(|error| error.diagnostic(&data));
+        // done before the answer is sent, for the next request not to find it running
+        drop(running);
+        // nobody listens after the time limit
+        let _ = sender.send(solved);
+    });
+    let solved = match receiver.recv_timeout(limits.timeout) {
+        Ok(solved) => solved.map_err(ApiError::Invalid)?,
+        Err(RecvTimeoutError::Timeout) => return Err(ApiError::Timeout(limits.timeout)),
+        Err(RecvTimeoutError::Disconnected) => return Err(ApiError::Panicked),
+    };
+    let reply = Reply {
+        record: Record::new(day, &input, &solved.answers[0]),
+        parse_ns: solved.parse_time.as_nanos() as u64,
+    };
+    Ok(serde_json::to_value(reply).unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{timeout_secs, Server};
+    use crate::answers::input_hash;
+    use crate::fetch::agent;
+    use crate::generate::generate;
+    use crate::runner::DAYS;
+    use serde_json::{json, Value};
+    use std::time::Duration;
+
+    /// Serves in the background, and gives the base URL.
+    fn start(server: Server) -> String {
+        let url = format!("http://{}", server.addr());
+        std::thread::spawn(move || server.serve());
+        url
+    }
+
+    /// Status and JSON body of the response.
+    fn call(method: &str, url: &str, body: &str) -> (u16, Value) {
+        let response = match agent().request(method, url).send_string(body) {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(error) => panic!("{error}"),
+        };
+        let status = response.status();
+        (
+            status,
+            serde_json::from_str(&response.into_string().unwrap()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn lists_the_days_and_parts() {
+        let url = start(Server::bind("127.0.0.1:0").unwrap());
+        let (status, days) = call("GET", &format!("{url}/days"), "");
+        assert_eq!(status, 200);
+        assert_eq!(days[0], json!({"day": 1, "parts": [1, 2]}));
+        assert_eq!(days.as_array().unwrap().len(), DAYS.count());
+    }
+
+    #[test]
+    fn solves_a_part_on_the_input_sent() {
+        let url = start(Server::bind("127.0.0.1:0").unwrap());
+        let sample = include_str!("../inputs/day07.sample.txt");
+        let (status, reply) = call("POST", &format!("{url}/days/7/parts/2"), sample);
+        assert_eq!(status, 200);
+        assert_eq!(reply["day"], 7);
+        assert_eq!(reply["part"], 2);
+        assert_eq!(reply["answer"], 24_933_642);
+        assert_eq!(reply["input"], input_hash(sample));
+        assert!(reply["elapsed_ns"].is_u64() && reply["parse_ns"].is_u64());
+
+        let sample = include_str!("../inputs/day15.sample.txt");
+        let (_, reply) = call("POST", &format!("{url}/days/15/parts/1?row=10"), sample);
+        assert_eq!(reply["answer"], 26);
+    }
+
+    #[test]
+    fn wrong_requests_are_explained() {
+        let url = start(Server::bind("127.0.0.1:0").unwrap());
+        let sample = include_str!("../inputs/day07.sample.txt");
+        let unknown_day = format!("/days/{}/parts/1", DAYS.end() + 1);
+        for (method, path, body, expected) in [
+            ("POST", unknown_day.as_str(), sample, 404),
+            ("POST", "/days/7/parts/3", sample, 404),
+            ("GET", "/days/7/parts/1", "", 405),
+            ("GET", "/answers", "", 404),
+            ("POST", "/days/15/parts/1?rows=10", sample, 400),
+            ("POST", "/days/15/parts/1?row=ten", sample, 400),
+            ("POST", "/days/1/parts/1", "12\nlots\n", 422),
+        ] {
+            let (status, reply) = call(method, &format!("{url}{path}"), body);
+            assert_eq!(status, expected, "{method} {path}: {reply}");
+            assert!(reply["error"].is_string());
+        }
+        let (_, reply) = call("POST", &format!("{url}/days/1/parts/1"), "12\nlots\n");
+        assert!(reply["error"].as_str().unwrap().contains("line 2"));
+    }
+
+    #[test]
+    fn limits_are_enforced() {
+        let server = Server::bind("127.0.0.1:0").unwrap().max_input(100);
+        let url = start(server);
+        let sample = include_str!("../inputs/day07.sample.txt");
+        let (status, reply) = call("POST", &format!("{url}/days/7/parts/1"), sample);
+        assert_eq!(status, 413);
+        assert_eq!(reply["error"], "input larger than 100 bytes");
+
+        let server = Server::bind("127.0.0.1:0")
+            .unwrap()
+            .timeout(Duration::from_millis(1))
+            .max_running(1);
+        let url = start(server);
+        let slow = generate(11, 1000, 1).unwrap();
+        let (status, reply) = call("POST", &format!("{url}/days/11/parts/2"), &slow);
+        assert_eq!(status, 503);
+        assert_eq!(reply["error"], "no answer within 1ms");
+        // still running in the background
+        let (status, reply) = call("POST", &format!("{url}/days/11/parts/2"), &slow);
+        assert_eq!(status, 503);
+        assert_eq!(reply["error"], "too many solves running, try again later");
+    }
+
+    #[test]
+    fn timeout_is_a_positive_number_of_seconds() {
+        assert_eq!(timeout_secs("2.5"), Ok(2.5));
+        for text in ["0", "-1", "nan", "inf", "1e30", "ten"] {
+            assert!(timeout_secs(text).is_err(), "{text}");
+        }
+    }
+}
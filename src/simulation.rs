@@ -0,0 +1,221 @@
+//! The puzzles solved step by step, to be watched one step at a time with `aoc play`.
+
+use crate::day05::{self, Day05};
+use crate::day09::{self, Day09};
+use crate::day10::{self, Day10};
+use crate::day11::{self, Day11};
+use crate::day14::{self, Day14};
+use crate::parse::ParseError;
+use crate::runner::Part;
+use crate::Solution;
+
+/// Days that can be watched step by step.
+pub const DAYS: [u8; 5] = [5, 9, 10, 11, 14];
+
+/// The state of a puzzle being solved step by step.
+pub trait Simulation {
+    /// Does the next step, or tells that the simulation is over.
+    fn step(&mut self) -> bool;
+
+    /// The current state, drawn like in the diagnostics of the day.
+    fn render(&self) -> String;
+
+    /// Where the simulation is, like the last move made.
+    fn status(&self) -> String;
+
+    fn boxed_clone(&self) -> Box<dyn Simulation>;
+}
+
+/// The simulation of a part of `day` on the input `data`, if the day is a simulation.
+pub fn simulation(
+    day: u8,
+    part: Part,
+    data: &str,
+) -> Option<Result<Box<dyn Simulation>, ParseError>> {
+    fn boxed<S: Simulation + 'static>(
+        simulation: Result<S, ParseError>,
+    ) -> Result<Box<dyn Simulation>, ParseError> {
+        simulation.map(|simulation| Box::new(simulation) as Box<dyn Simulation>)
+    }
+    Some(match day {
+        5 => boxed(Day05.parse(data).map(|puzzle| day05::crane(puzzle, part))),
+        9 => boxed(
+            Day09
+                .parse(data)
+                .map(|commands| day09::rope(commands, part)),
+        ),
+        10 => boxed(Day10.parse(data).map(|operations| day10::crt(&operations))),
+        11 => boxed(
+            Day11
+                .parse(data)
+                .map(|monkeys| day11::rounds(monkeys, part)),
+        ),
+        14 => boxed(Day14.parse(data).map(|game| day14::flow(game, part))),
+        _ => return None,
+    })
+}
+
+/// Steps between two saved states: going back restores the last one and steps forward again,
+/// rather than keeping every state.
+const CHECKPOINT_INTERVAL: usize = 256;
+
+/// A simulation that can go back to its previous steps.
+pub struct Timeline {
+    current: Box<dyn Simulation>,
+    step: usize,
+    /// the state at every multiple of [`CHECKPOINT_INTERVAL`] reached so far
+    checkpoints: Vec<Box<dyn Simulation>>,
+    /// number of steps, once the end was reached
+    len: Option<usize>,
+}
+
+impl Timeline {
+    pub fn new(simulation: Box<dyn Simulation>) -> Self {
+        Timeline {
+            checkpoints: vec![simulation.boxed_clone()],
+            current: simulation,
+            step: 0,
+            len: None,
+        }
+    }
+
+    /// Number of steps done since the start.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Number of steps of the whole simulation, once its end was reached.
+    pub fn last_step(&self) -> Option<usize> {
+        self.len
+    }
+
+    pub fn current(&self) -> &dyn Simulation {
+        self.current.as_ref()
+    }
+
+    /// Does the next step, unless the simulation is over.
+    pub fn forward(&mut self) -> bool {
+        if self.len == Some(self.step) || !self.current.step() {
+            self.len = Some(self.step);
+            return false;
+        }
+        self.step += 1;
+        if self.step.is_multiple_of(CHECKPOINT_INTERVAL)
+            && self.step / CHECKPOINT_INTERVAL == self.checkpoints.len()
+        {
+            self.checkpoints.push(self.current.boxed_clone());
+        }
+        true
+    }
+
+    /// Goes back one step, unless at the start.
+    pub fn back(&mut self) -> bool {
+        if self.step == 0 {
+            return false;
+        }
+        self.go_to(self.step - 1);
+        true
+    }
+
+    /// Goes to `step`, or to the end if the simulation is shorter.
+    pub fn go_to(&mut self, step: usize) {
+        let checkpoint = (step / CHECKPOINT_INTERVAL).min(self.checkpoints.len() - 1);
+        if step < self.step || checkpoint * CHECKPOINT_INTERVAL > self.step {
+            self.current = self.checkpoints[checkpoint].boxed_clone();
+            self.step = checkpoint * CHECKPOINT_INTERVAL;
+        }
+        while self.step < step && self.forward() {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{simulation, Simulation, Timeline, CHECKPOINT_INTERVAL, DAYS};
+    use crate::runner::Part;
+
+    /// Counts up to a limit.
+    #[derive(Clone)]
+    struct Counter {
+        count: usize,
+        limit: usize,
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            self.count += 1;
+            self.count <= self.limit
+        }
+
+        fn render(&self) -> String {
+            self.count.to_string()
+        }
+
+        fn status(&self) -> String {
+            format!("{} of {}", self.count, self.limit)
+        }
+
+        fn boxed_clone(&self) -> Box<dyn Simulation> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[test]
+    fn timeline_goes_back_and_forth() {
+        let limit = 3 * CHECKPOINT_INTERVAL + 10;
+        let mut timeline = Timeline::new(Box::new(Counter { count: 0, limit }));
+        assert!(!timeline.back());
+        assert!(timeline.forward());
+        assert_eq!(timeline.current().render(), "1");
+
+        timeline.go_to(2 * CHECKPOINT_INTERVAL + 5);
+        assert_eq!(timeline.step(), 2 * CHECKPOINT_INTERVAL + 5);
+        assert_eq!(timeline.checkpoints.len(), 3);
+        assert!(timeline.back());
+        assert_eq!(
+            timeline.current().render(),
+            (2 * CHECKPOINT_INTERVAL + 4).to_string()
+        );
+
+        timeline.go_to(usize::MAX);
+        assert_eq!(
+            (timeline.step(), timeline.last_step()),
+            (limit, Some(limit))
+        );
+        assert!(!timeline.forward());
+        timeline.go_to(7);
+        assert_eq!(timeline.current().render(), "7");
+        timeline.go_to(CHECKPOINT_INTERVAL * 3 + 1);
+        assert_eq!(
+            timeline.current().render(),
+            (CHECKPOINT_INTERVAL * 3 + 1).to_string()
+        );
+    }
+
+    #[test]
+    fn simulations_end_with_the_answers() {
+        let samples = [
+            (5, include_str!("../inputs/day05.sample.txt")),
+            (9, include_str!("../inputs/day09.sample1.txt")),
+            (10, include_str!("../inputs/day10.sample.txt")),
+            (11, include_str!("../inputs/day11.sample.txt")),
+            (14, include_str!("../inputs/day14.sample.txt")),
+        ];
+        let expected = [
+            ("CMZ", "MCD"),
+            ("13", "1"),
+            ("13140", "13140"),
+            ("10605", "2713310158"),
+            ("24", "93"),
+        ];
+        assert_eq!(samples.map(|(day, _)| day), DAYS);
+        for ((day, data), answers) in samples.into_iter().zip(expected) {
+            for (part, answer) in Part::BOTH.into_iter().zip([answers.0, answers.1]) {
+                let mut timeline = Timeline::new(simulation(day, part, data).unwrap().unwrap());
+                timeline.go_to(usize::MAX);
+                let status = timeline.current().status();
+                assert!(status.contains(answer), "day {day} part {part}: {status}");
+            }
+        }
+        assert!(simulation(1, Part::One, "").is_none());
+    }
+}
@@ -0,0 +1,79 @@
+//! A local stand-in for the Advent of Code site, for the tests of the commands talking to it.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use tiny_http::{Header, Response, Server};
+
+/// What the stand-in received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub url: String,
+    pub cookie: String,
+    pub body: String,
+}
+
+/// Serves a fixed list of responses, one per request, then stops.
+pub struct StandIn {
+    /// URL of the 2022 event on the stand-in
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+    thread: JoinHandle<()>,
+}
+
+impl StandIn {
+    /// Gives the `responses` (status, body, `Retry-After`) in order.
+    pub fn serve(responses: Vec<(u16, &'static str, Option<&'static str>)>) -> Self {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/2022", server.server_addr());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let seen = Arc::clone(&requests);
+        let thread = std::thread::spawn(move || {
+            for (status, body, retry_after) in responses {
+                let mut request = server.recv().unwrap();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string())
+                    .unwrap_or_default();
+                let mut content = String::new();
+                request.as_reader().read_to_string(&mut content).unwrap();
+                seen.lock().unwrap().push(Request {
+                    url: request.url().to_string(),
+                    cookie,
+                    body: content,
+                });
+                let mut response = Response::from_string(body).with_status_code(status);
+                if let Some(secs) = retry_after {
+                    response.add_header(Header::from_bytes("Retry-After", secs).unwrap());
+                }
+                request.respond(response).unwrap();
+            }
+        });
+        StandIn {
+            base_url,
+            requests,
+            thread,
+        }
+    }
+
+    /// The requests, once every response was given.
+    pub fn requests(self) -> Vec<Request> {
+        self.thread.join().unwrap();
+        Arc::try_unwrap(self.requests)
+            .unwrap()
+            .into_inner()
+            .unwrap()
+    }
+}
+
+/// An empty directory for the files of a test, under the temporary directory: the leftovers of
+/// a previous run are removed.
+pub fn temp_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-{}-{test}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
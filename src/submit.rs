@@ -0,0 +1,450 @@
+//! Submission of the answers to the site, with a local history of the attempts so that an
+//! answer known to be wrong is never submitted again.
+
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::answers::{load_json, part_number, save_json, JsonFileError};
+use crate::fetch::{self, FetchError};
+use crate::runner::Part;
+
+/// The attempts of whoever works in this copy of the repository; not checked in.
+pub const HISTORY_PATH: &str = "submissions.json";
+
+/// What the site replied to an answer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, without a hint
+    Wrong,
+    /// not judged: the previous answer was too recent
+    Wait,
+    /// not judged: the part was already solved
+    AlreadySolved,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait => write!(f, "not judged, too soon after the previous answer"),
+            Verdict::AlreadySolved => write!(f, "not judged, the part is already solved"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    pub verdict: Verdict,
+    /// how long to wait before answering again
+    pub wait: Option<Duration>,
+    /// the text of the reply
+    pub message: String,
+}
+
+/// Tells the verdict from the page replying to an answer.
+pub fn parse_reply(page: &str) -> Option<Reply> {
+    static TAG: OnceLock<Regex> = OnceLock::new();
+    let start = page.find("<article")?;
+    let end = page[start..].find("</article>")? + start;
+    let tag = TAG.get_or_init(|| Regex::new("<[^>]*>").unwrap());
+    let text = tag.replace_all(&page[start..end], "");
+    let message = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("answer is too high") {
+        Verdict::TooHigh
+    } else if message.contains("answer is too low") {
+        Verdict::TooLow
+    } else if message.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::Wait
+    } else if message.contains("Did you already complete it") {
+        Verdict::AlreadySolved
+    } else {
+        return None;
+    };
+    Some(Reply {
+        verdict,
+        wait: wait_time(&message),
+        message,
+    })
+}
+
+/// The delay asked for in a reply: `You have 4m 23s left to wait` after an answer too soon,
+/// or `please wait 5 minutes before trying again` after a wrong one.
+fn wait_time(message: &str) -> Option<Duration> {
+    static LEFT: OnceLock<Regex> = OnceLock::new();
+    static MINUTES: OnceLock<Regex> = OnceLock::new();
+    let left = LEFT.get_or_init(|| Regex::new(r"You have (?:(\d+)m )?(\d+)s left").unwrap());
+    let minutes =
+        MINUTES.get_or_init(|| Regex::new(r"(?i)wait (one|\d+) minutes? before").unwrap());
+    if let Some(captures) = left.captures(message) {
+        let number = |idx| {
+            captures
+                .get(idx)
+                .map_or(Ok(0), |m| m.as_str().parse::<u64>())
+        };
+        return Some(Duration::from_secs(number(1).ok()? * 60 + number(2).ok()?));
+    }
+    let captures = minutes.captures(message)?;
+    let count = match &captures[1] {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    Some(Duration::from_secs(count * 60))
+}
+
+/// An answer sent to the site, and its verdict.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    #[serde(with = "part_number")]
+    pub part: Part,
+    /// hash of the input the answer was computed from
+    pub input: String,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// seconds since the Unix epoch
+    pub at: u64,
+    /// when the site accepts answers again, in seconds since the Unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_at: Option<u64>,
+}
+
+/// Why an answer is not worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    Solved { answer: String },
+    AlreadyJudged { answer: String, verdict: Verdict },
+    AboveTooHigh { answer: String, bound: String },
+    BelowTooLow { answer: String, bound: String },
+    TooSoon { wait: Duration },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved { answer } => write!(f, "already solved, with {answer}"),
+            Refusal::AlreadyJudged { answer, verdict } => {
+                write!(f, "{answer} was already submitted, and was {verdict}")
+            }
+            Refusal::AboveTooHigh { answer, bound } => {
+                write!(f, "{answer} is too high: {bound} already was")
+            }
+            Refusal::BelowTooLow { answer, bound } => {
+                write!(f, "{answer} is too low: {bound} already was")
+            }
+            Refusal::TooSoon { wait } => write!(
+                f,
+                "the site accepts answers again in {}s",
+                wait.as_secs().max(1)
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the attempts from `path`; a missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, JsonFileError> {
+        Ok(History {
+            attempts: load_json(path)?,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), JsonFileError> {
+        save_json(path, &self.attempts)
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Checks that `answer` can still be right, given the previous attempts on the same
+    /// input, and that the site accepts answers at `now` (seconds since the Unix epoch).
+    pub fn check(
+        &self,
+        day: u8,
+        part: Part,
+        input: &str,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Refusal> {
+        let same_puzzle = || {
+            self.attempts
+                .iter()
+                .filter(move |a| a.day == day && a.part == part && a.input == input)
+        };
+        if let Some(solved) = same_puzzle().find(|a| a.verdict == Verdict::Correct) {
+            return Err(Refusal::Solved {
+                answer: solved.answer.clone(),
+            });
+        }
+        let judged = |a: &&Attempt| !matches!(a.verdict, Verdict::Wait | Verdict::AlreadySolved);
+        if let Some(known) = same_puzzle().filter(judged).find(|a| a.answer == answer) {
+            return Err(Refusal::AlreadyJudged {
+                answer: String::from(answer),
+                verdict: known.verdict,
+            });
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |verdict| {
+                same_puzzle()
+                    .filter(move |a| a.verdict == verdict)
+                    .filter_map(|a| Some((a.answer.parse::<i128>().ok()?, &a.answer)))
+            };
+            if let Some((_, high)) = bound(Verdict::TooHigh).filter(|&(b, _)| value >= b).min() {
+                return Err(Refusal::AboveTooHigh {
+                    answer: String::from(answer),
+                    bound: high.clone(),
+                });
+            }
+            if let Some((_, low)) = bound(Verdict::TooLow).filter(|&(b, _)| value <= b).max() {
+                return Err(Refusal::BelowTooLow {
+                    answer: String::from(answer),
+                    bound: low.clone(),
+                });
+            }
+        }
+
+        // the delay after an answer is for the whole account, whatever the puzzle
+        let retry_at = self.attempts.iter().filter_map(|a| a.retry_at).max();
+        match retry_at {
+            Some(retry_at) if retry_at > now => Err(Refusal::TooSoon {
+                wait: Duration::from_secs(retry_at - now),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Sends answers to the site.
+pub struct Submitter {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Submitter {
+    pub fn new(base_url: &str, session: String) -> Self {
+        Submitter {
+            base_url: String::from(base_url.trim_end_matches('/')),
+            session,
+            agent: fetch::agent(),
+        }
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Reply, FetchError> {
+        let url = format!("{}/day/{day}/answer", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        let response = match response {
+            Ok(response) => response,
+            Err(ureq::Error::Status(status, response)) => {
+                let page = response.into_string().unwrap_or_default();
+                if page.to_lowercase().contains("log in") {
+                    return Err(FetchError::LoginRequired { url });
+                }
+                let message = page.lines().next().unwrap_or("").trim().chars().take(200);
+                return Err(FetchError::Status {
+                    url,
+                    status,
+                    message: message.collect(),
+                });
+            }
+            Err(ureq::Error::Transport(error)) => {
+                return Err(FetchError::Transport {
+                    url,
+                    message: error.to_string(),
+                })
+            }
+        };
+        let page = response
+            .into_string()
+            .map_err(|error| FetchError::Transport {
+                url: url.clone(),
+                message: error.to_string(),
+            })?;
+        match parse_reply(&page) {
+            Some(reply) => Ok(reply),
+            None if page.to_lowercase().contains("log in") => {
+                Err(FetchError::LoginRequired { url })
+            }
+            None => Err(FetchError::UnexpectedPage { url }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_reply, Attempt, History, Refusal, Submitter, Verdict};
+    use crate::fetch::FetchError;
+    use crate::runner::Part;
+    use crate::stand_in::StandIn;
+    use std::time::Duration;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main>\n<article><p>{article}</p></article>\n</main></body></html>")
+    }
+
+    fn attempt(part: Part, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            day: 1,
+            part,
+            input: String::from("0123456789abcdef"),
+            answer: String::from(answer),
+            verdict,
+            at: 1_000,
+            retry_at: None,
+        }
+    }
+
+    #[test]
+    fn verdicts_of_the_replies() {
+        let verdict = |article: &str| parse_reply(&page(article)).map(|r| (r.verdict, r.wait));
+        assert_eq!(
+            verdict("That's the right answer!  You are <em>one gold star</em> closer."),
+            Some((Verdict::Correct, None))
+        );
+        assert_eq!(
+            verdict(
+                "That's not the right answer; your answer is too high.  If you're stuck, \
+                 make sure you're using the full input data; please wait one minute before \
+                 trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a>"
+            ),
+            Some((Verdict::TooHigh, Some(Duration::from_secs(60))))
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too low."),
+            Some((Verdict::TooLow, None))
+        );
+        assert_eq!(
+            verdict("That's not the right answer.  Please wait 5 minutes before trying again."),
+            Some((Verdict::Wrong, Some(Duration::from_secs(300))))
+        );
+        assert_eq!(
+            verdict(
+                "You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again.  You have 4m 23s left to wait."
+            ),
+            Some((Verdict::Wait, Some(Duration::from_secs(263))))
+        );
+        assert_eq!(
+            verdict("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some((Verdict::AlreadySolved, None))
+        );
+        assert_eq!(verdict("Something else"), None);
+        assert_eq!(parse_reply("<html>no article</html>"), None);
+    }
+
+    #[test]
+    fn refuse_the_answers_known_to_be_wrong() {
+        let mut history = History::default();
+        history.record(attempt(Part::One, "5000", Verdict::TooHigh));
+        history.record(attempt(Part::One, "4000", Verdict::TooHigh));
+        history.record(attempt(Part::One, "1000", Verdict::TooLow));
+        history.record(attempt(Part::One, "ABC", Verdict::Wrong));
+        let check = |answer| history.check(1, Part::One, "0123456789abcdef", answer, 2_000);
+
+        assert_eq!(check("2500"), Ok(()));
+        assert_eq!(
+            check("ABC"),
+            Err(Refusal::AlreadyJudged {
+                answer: String::from("ABC"),
+                verdict: Verdict::Wrong
+            })
+        );
+        assert_eq!(
+            check("4500"),
+            Err(Refusal::AboveTooHigh {
+                answer: String::from("4500"),
+                bound: String::from("4000")
+            })
+        );
+        assert_eq!(
+            check("999"),
+            Err(Refusal::BelowTooLow {
+                answer: String::from("999"),
+                bound: String::from("1000")
+            })
+        );
+        // another part, or another input, has its own bounds
+        assert_eq!(
+            history.check(1, Part::Two, "0123456789abcdef", "4500", 2_000),
+            Ok(())
+        );
+        assert_eq!(history.check(1, Part::One, "other", "4500", 2_000), Ok(()));
+
+        history.record(attempt(Part::One, "2500", Verdict::Correct));
+        assert_eq!(
+            history.check(1, Part::One, "0123456789abcdef", "2600", 2_000),
+            Err(Refusal::Solved {
+                answer: String::from("2500")
+            })
+        );
+    }
+
+    #[test]
+    fn wait_as_long_as_the_site_asked() {
+        let mut history = History::default();
+        history.record(Attempt {
+            retry_at: Some(1_060),
+            ..attempt(Part::Two, "12", Verdict::Wrong)
+        });
+        assert_eq!(
+            history.check(2, Part::One, "other", "7", 1_030),
+            Err(Refusal::TooSoon {
+                wait: Duration::from_secs(30)
+            })
+        );
+        assert_eq!(history.check(2, Part::One, "other", "7", 1_060), Ok(()));
+    }
+
+    #[test]
+    fn post_the_answer_to_the_stand_in() {
+        let right = "<article><p>That's the right answer!</p></article>";
+        let site = StandIn::serve(vec![
+            (200, right, None),
+            (200, "<html>Please log in</html>", None),
+            (200, "<html>Advent of Code</html>", None),
+        ]);
+        let submitter = Submitter::new(&site.base_url, String::from("53cr3t"));
+        let reply = submitter.submit(7, Part::Two, "24933642").unwrap();
+        assert_eq!(reply.verdict, Verdict::Correct);
+        assert!(matches!(
+            submitter.submit(7, Part::Two, "24933642"),
+            Err(FetchError::LoginRequired { .. })
+        ));
+        assert!(matches!(
+            submitter.submit(7, Part::Two, "24933642"),
+            Err(FetchError::UnexpectedPage { .. })
+        ));
+
+        let requests = site.requests();
+        assert_eq!(requests[0].url, "/2022/day/7/answer");
+        assert_eq!(requests[0].cookie, "session=53cr3t");
+        assert_eq!(requests[0].body, "level=2&answer=24933642");
+    }
+}
@@ -0,0 +1,489 @@
+//! A terminal player for the simulations: one step at a time, back and forth, or played at a
+//! chosen speed.
+//!
+//! [`Player`] handles the keys and lays the screen out; [`run`] puts the terminal in raw mode
+//! and draws with the ANSI escape sequences every Linux terminal understands.
+
+use std::time::Duration;
+
+use crate::simulation::Timeline;
+
+/// The keys the player responds to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Key {
+    Char(char),
+    Left,
+    Right,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Enter,
+    Backspace,
+    Escape,
+    /// Ctrl-C
+    Interrupt,
+}
+
+/// The keys typed, from the bytes read from a terminal in raw mode.
+pub fn decode_keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys = vec![];
+    let mut rest = bytes;
+    while let Some(&byte) = rest.first() {
+        let (key, len) = match rest {
+            [0x1b, b'[' | b'O', code, ..] if code.is_ascii_uppercase() => {
+                let key = match code {
+                    b'A' => Some(Key::Up),
+                    b'B' => Some(Key::Down),
+                    b'C' => Some(Key::Right),
+                    b'D' => Some(Key::Left),
+                    b'H' => Some(Key::Home),
+                    b'F' => Some(Key::End),
+                    _ => None,
+                };
+                (key, 3)
+            }
+            [0x1b, b'[', number @ b'0'..=b'9', b'~', ..] => {
+                let key = match number {
+                    b'1' | b'7' => Some(Key::Home),
+                    b'4' | b'8' => Some(Key::End),
+                    b'5' => Some(Key::PageUp),
+                    b'6' => Some(Key::PageDown),
+                    _ => None,
+                };
+                (key, 4)
+            }
+            [0x1b, b'[', ..] => {
+                // an unknown sequence: skipped up to its final byte
+                let len = rest[2..]
+                    .iter()
+                    .position(|b| (0x40..=0x7e).contains(b))
+                    .map_or(rest.len(), |end| end + 3);
+                (None, len)
+            }
+            [0x1b, ..] => (Some(Key::Escape), 1),
+            [b'\r' | b'\n', ..] => (Some(Key::Enter), 1),
+            [0x7f | 0x08, ..] => (Some(Key::Backspace), 1),
+            [0x03, ..] => (Some(Key::Interrupt), 1),
+            _ => {
+                let len = match byte {
+                    0xf0.. => 4,
+                    0xe0.. => 3,
+                    0xc0.. => 2,
+                    _ => 1,
+                };
+                let len = len.min(rest.len());
+                let key = std::str::from_utf8(&rest[..len])
+                    .ok()
+                    .and_then(|text| text.chars().next())
+                    .filter(|ch| !ch.is_control())
+                    .map(Key::Char);
+                (key, len)
+            }
+        };
+        keys.extend(key);
+        rest = &rest[len..];
+    }
+    keys
+}
+
+/// Speeds of the playback, in steps per second.
+const SPEEDS: [u32; 12] = [1, 2, 5, 10, 20, 50, 100, 200, 500, 1000, 5000, 20000];
+
+/// Shortest time between two frames while playing: faster speeds do several steps per frame.
+const FRAME: Duration = Duration::from_millis(20);
+
+const HELP: &str =
+    "←/→ step  space play/pause  +/- speed  g go to step  Home/End  ↑/↓/PgUp/PgDn/</> scroll  q quit";
+
+/// What the player shows, and how it responds to the keys.
+pub struct Player {
+    title: String,
+    timeline: Timeline,
+    playing: bool,
+    /// index in [`SPEEDS`]
+    speed: usize,
+    /// first row and column of the state shown
+    scroll: (usize, usize),
+    /// the step being typed after `g`
+    prompt: Option<String>,
+}
+
+impl Player {
+    pub fn new(title: &str, timeline: Timeline) -> Self {
+        Player {
+            title: String::from(title),
+            timeline,
+            playing: false,
+            speed: 3,
+            scroll: (0, 0),
+            prompt: None,
+        }
+    }
+
+    pub fn timeline(&self) -> &Timeline {
+        &self.timeline
+    }
+
+    /// Responds to a key, and tells whether to go on.
+    pub fn handle(&mut self, key: Key) -> bool {
+        if let Some(prompt) = &mut self.prompt {
+            match key {
+                Key::Char(digit @ '0'..='9') => prompt.push(digit),
+                Key::Backspace => {
+                    prompt.pop();
+                }
+                Key::Enter => {
+                    if let Ok(step) = prompt.parse() {
+                        self.timeline.go_to(step);
+                    }
+                    self.prompt = None;
+                }
+                Key::Escape => self.prompt = None,
+                Key::Interrupt => return false,
+                _ => {}
+            }
+            return true;
+        }
+        match key {
+            Key::Char('q') | Key::Escape | Key::Interrupt => return false,
+            Key::Right | Key::Char('l') | Key::Char('n') => {
+                self.playing = false;
+                self.timeline.forward();
+            }
+            Key::Left | Key::Char('h') | Key::Char('p') => {
+                self.playing = false;
+                self.timeline.back();
+            }
+            Key::Home => {
+                self.playing = false;
+                self.timeline.go_to(0);
+            }
+            Key::End => {
+                self.playing = false;
+                self.timeline.go_to(usize::MAX);
+            }
+            Key::Char(' ') => {
+                self.playing =
+                    !self.playing && self.timeline.last_step() != Some(self.timeline.step());
+            }
+            Key::Char('+') | Key::Char('=') => self.speed = (self.speed + 1).min(SPEEDS.len() - 1),
+            Key::Char('-') => self.speed = self.speed.saturating_sub(1),
+            Key::Char('g') => self.prompt = Some(String::new()),
+            Key::Up | Key::Char('k') => self.scroll.0 = self.scroll.0.saturating_sub(1),
+            Key::Down | Key::Char('j') => self.scroll.0 += 1,
+            Key::PageUp => self.scroll.0 = self.scroll.0.saturating_sub(20),
+            Key::PageDown => self.scroll.0 += 20,
+            Key::Char('<') => self.scroll.1 = self.scroll.1.saturating_sub(10),
+            Key::Char('>') => self.scroll.1 += 10,
+            _ => {}
+        }
+        true
+    }
+
+    /// Time until the next frame, while playing.
+    pub fn frame_time(&self) -> Option<Duration> {
+        self.playing.then(|| {
+            Duration::from_secs(1)
+                .div_f64(f64::from(SPEEDS[self.speed]))
+                .max(FRAME)
+        })
+    }
+
+    /// Plays the steps of a frame, and stops at the end.
+    pub fn tick(&mut self) {
+        let steps = (f64::from(SPEEDS[self.speed]) * FRAME.as_secs_f64()).ceil() as usize;
+        for _ in 0..steps {
+            if !self.timeline.forward() {
+                self.playing = false;
+                break;
+            }
+        }
+    }
+
+    /// The lines of the screen: a status line, the state from the scroll position, and the
+    /// help or the prompt at the bottom.
+    pub fn screen(&self, width: usize, height: usize) -> Vec<String> {
+        let clip =
+            |line: &str, skip: usize| -> String { line.chars().skip(skip).take(width).collect() };
+        let total = self
+            .timeline
+            .last_step()
+            .map_or(String::from("?"), |len| len.to_string());
+        let state = if self.playing {
+            format!("playing at {}/s", SPEEDS[self.speed])
+        } else {
+            format!("paused, {}/s", SPEEDS[self.speed])
+        };
+        let status = format!(
+            "{}  step {}/{total}  {}  [{state}]",
+            self.title,
+            self.timeline.step(),
+            self.timeline.current().status()
+        );
+
+        let mut lines = vec![clip(&status, 0)];
+        let render = self.timeline.current().render();
+        lines.extend(
+            render
+                .lines()
+                .skip(self.scroll.0)
+                .take(height.saturating_sub(2))
+                .map(|line| clip(line, self.scroll.1)),
+        );
+        lines.resize(height.saturating_sub(1).max(1), String::new());
+        let bottom = match &self.prompt {
+            Some(prompt) => format!("go to step: {prompt}"),
+            None => String::from(HELP),
+        };
+        lines.push(clip(&bottom, 0));
+        lines.truncate(height);
+        lines
+    }
+}
+
+/// Plays on the terminal until the user quits.
+#[cfg(unix)]
+pub fn run(player: &mut Player) -> std::io::Result<()> {
+    terminal::run(player)
+}
+
+/// Plays on the terminal until the user quits.
+#[cfg(not(unix))]
+pub fn run(_player: &mut Player) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "the player needs a Unix terminal",
+    ))
+}
+
+#[cfg(unix)]
+mod terminal {
+    use std::fs::File;
+    use std::io::{IsTerminal, Read, Write};
+    use std::os::fd::AsRawFd;
+    use std::time::{Duration, Instant};
+
+    use super::{decode_keys, Player};
+
+    /// The terminal in raw mode on the alternate screen, given back as it was when dropped,
+    /// even after a panic. Keys are read from the terminal itself rather than stdin, which
+    /// may be the input of the puzzle.
+    struct RawMode {
+        tty: File,
+        saved: libc::termios,
+    }
+
+    impl RawMode {
+        fn enable() -> std::io::Result<Self> {
+            let tty = File::options().read(true).write(true).open("/dev/tty")?;
+            let fd = tty.as_raw_fd();
+            let mut termios = std::mem::MaybeUninit::<libc::termios>::uninit();
+            // SAFETY: tcgetattr fills the structure when it succeeds
+            let saved = unsafe {
+                if libc::tcgetattr(fd, termios.as_mut_ptr()) != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                termios.assume_init()
+            };
+            let mut raw = saved;
+            raw.c_lflag &= !(libc::ICANON | libc::ECHO | libc::ISIG | libc::IEXTEN);
+            raw.c_iflag &= !(libc::IXON | libc::ICRNL);
+            raw.c_cc[libc::VMIN] = 0;
+            raw.c_cc[libc::VTIME] = 0;
+            // SAFETY: raw is a valid termios, copied from the current one
+            if unsafe { libc::tcsetattr(fd, libc::TCSAFLUSH, &raw) } != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            // alternate screen, hidden cursor
+            print!("\x1b[?1049h\x1b[?25l");
+            std::io::stdout().flush()?;
+            Ok(RawMode { tty, saved })
+        }
+    }
+
+    impl Drop for RawMode {
+        fn drop(&mut self) {
+            print!("\x1b[?25h\x1b[?1049l");
+            let _ = std::io::stdout().flush();
+            // SAFETY: saved is the termios read when entering raw mode
+            unsafe { libc::tcsetattr(self.tty.as_raw_fd(), libc::TCSAFLUSH, &self.saved) };
+        }
+    }
+
+    /// Width and height of the terminal.
+    fn size() -> (usize, usize) {
+        let mut size = std::mem::MaybeUninit::<libc::winsize>::zeroed();
+        // SAFETY: TIOCGWINSZ fills a winsize
+        let size = unsafe {
+            if libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, size.as_mut_ptr()) != 0 {
+                return (80, 24);
+            }
+            size.assume_init()
+        };
+        match (size.ws_col, size.ws_row) {
+            (0, _) | (_, 0) => (80, 24),
+            (width, height) => (usize::from(width), usize::from(height)),
+        }
+    }
+
+    /// Waits at most `timeout` for keys, and gives the bytes typed.
+    fn read_keys(tty: &mut File, timeout: Option<Duration>) -> std::io::Result<Vec<u8>> {
+        let mut poll = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout = timeout.map_or(-1, |timeout| timeout.as_millis().min(60_000) as i32);
+        // SAFETY: a single valid pollfd
+        match unsafe { libc::poll(&mut poll, 1, timeout) } {
+            -1 => {
+                let error = std::io::Error::last_os_error();
+                if error.kind() == std::io::ErrorKind::Interrupted {
+                    // the terminal was resized
+                    return Ok(vec![]);
+                }
+                Err(error)
+            }
+            0 => Ok(vec![]),
+            _ => {
+                let mut buffer = [0; 64];
+                let len = tty.read(&mut buffer)?;
+                Ok(buffer[..len].to_vec())
+            }
+        }
+    }
+
+    fn draw(player: &Player) -> std::io::Result<()> {
+        let (width, height) = size();
+        let mut frame = String::from("\x1b[H");
+        for (idx, line) in player.screen(width, height).iter().enumerate() {
+            if idx > 0 {
+                frame.push_str("\r\n");
+            }
+            frame.push_str(line);
+            frame.push_str("\x1b[K");
+        }
+        frame.push_str("\x1b[J");
+        let mut stdout = std::io::stdout().lock();
+        stdout.write_all(frame.as_bytes())?;
+        stdout.flush()
+    }
+
+    pub fn run(player: &mut Player) -> std::io::Result<()> {
+        if !std::io::stdout().is_terminal() {
+            return Err(std::io::Error::other("the player needs a terminal"));
+        }
+        let mut raw_mode = RawMode::enable()?;
+        let mut next_frame = Instant::now();
+        loop {
+            draw(player)?;
+            let timeout = player
+                .frame_time()
+                .map(|_| next_frame.saturating_duration_since(Instant::now()));
+            let bytes = read_keys(&mut raw_mode.tty, timeout)?;
+            for key in decode_keys(&bytes) {
+                if !player.handle(key) {
+                    return Ok(());
+                }
+            }
+            if let Some(frame_time) = player.frame_time() {
+                if Instant::now() >= next_frame {
+                    player.tick();
+                    next_frame = Instant::now() + frame_time;
+                }
+            } else {
+                next_frame = Instant::now();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode_keys, Key, Player, HELP};
+    use crate::runner::Part;
+    use crate::simulation::{simulation, Timeline};
+
+    #[test]
+    fn keys_from_the_terminal() {
+        assert_eq!(
+            decode_keys(b"\x1b[C\x1b[Dq 7\r\x1b\x7f\x03"),
+            vec![
+                Key::Right,
+                Key::Left,
+                Key::Char('q'),
+                Key::Char(' '),
+                Key::Char('7'),
+                Key::Enter,
+                Key::Escape,
+                Key::Backspace,
+                Key::Interrupt
+            ]
+        );
+        assert_eq!(
+            decode_keys(b"\x1b[5~\x1b[6~\x1bOH\x1b[4~\x1b[1;5Cg"),
+            vec![
+                Key::PageUp,
+                Key::PageDown,
+                Key::Home,
+                Key::End,
+                Key::Char('g')
+            ]
+        );
+        assert_eq!(decode_keys("é".as_bytes()), vec![Key::Char('é')]);
+    }
+
+    fn player() -> Player {
+        let data = include_str!("../inputs/day14.sample.txt");
+        let simulation = simulation(14, Part::One, data).unwrap().unwrap();
+        Player::new("Day 14 part 1", Timeline::new(simulation))
+    }
+
+    #[test]
+    fn step_jump_and_play() {
+        let mut player = player();
+        for key in decode_keys(b"\x1b[C\x1b[C\x1b[C\x1b[D") {
+            assert!(player.handle(key));
+        }
+        assert_eq!(player.timeline().step(), 2);
+
+        for key in decode_keys(b"g12x\x7f3\r") {
+            player.handle(key);
+        }
+        assert_eq!(player.timeline().step(), 13);
+        player.handle(Key::End);
+        assert_eq!(player.timeline().last_step(), Some(24));
+
+        player.handle(Key::Home);
+        player.handle(Key::Char(' '));
+        let frame_time = player.frame_time().unwrap();
+        for _ in 0..100 {
+            player.tick();
+        }
+        assert!(frame_time.as_millis() >= 20);
+        assert_eq!(player.timeline().step(), 24);
+        assert_eq!(player.frame_time(), None);
+        assert!(!player.handle(Key::Char('q')));
+    }
+
+    #[test]
+    fn screen_fits_the_terminal() {
+        let mut player = player();
+        player.handle(Key::End);
+        let screen = player.screen(30, 8);
+        assert_eq!(screen.len(), 8);
+        assert!(screen[0].starts_with("Day 14 part 1  step 24/24"));
+        assert!(screen.iter().all(|line| line.chars().count() <= 30));
+        assert_eq!(screen[7], HELP.chars().take(30).collect::<String>());
+
+        player.handle(Key::Char('>'));
+        player.handle(Key::Down);
+        let scrolled = player.screen(200, 8);
+        let render = player.timeline().current().render();
+        let second_row: String = render.lines().nth(1).unwrap().chars().skip(10).collect();
+        assert_eq!(scrolled[1], second_row);
+    }
+}
@@ -0,0 +1,71 @@
+//! Resources used by the whole process, as counted by the system: the CPU time of all its
+//! threads, and the most memory it held at once.
+
+use std::time::Duration;
+
+#[cfg(unix)]
+fn rusage() -> Option<libc::rusage> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::zeroed();
+    // SAFETY: getrusage fills the struct when it succeeds
+    unsafe {
+        if libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) != 0 {
+            return None;
+        }
+        Some(usage.assume_init())
+    }
+}
+
+/// The CPU time used by the process so far, by all its threads, in user and system mode.
+#[cfg(unix)]
+pub fn cpu_time() -> Option<Duration> {
+    let usage = rusage()?;
+    let time = |time: libc::timeval| Duration::new(time.tv_sec as u64, time.tv_usec as u32 * 1000);
+    Some(time(usage.ru_utime) + time(usage.ru_stime))
+}
+
+/// The most bytes of memory the process had resident at once so far.
+#[cfg(unix)]
+pub fn peak_resident() -> Option<u64> {
+    // in kilobytes, but in bytes on macOS
+    let unit = if cfg!(target_os = "macos") { 1 } else { 1024 };
+    Some(rusage()?.ru_maxrss as u64 * unit)
+}
+
+/// The CPU time used by the process so far, unknown on this platform.
+#[cfg(not(unix))]
+pub fn cpu_time() -> Option<Duration> {
+    None
+}
+
+/// The most memory the process had resident at once so far, unknown on this platform.
+#[cfg(not(unix))]
+pub fn peak_resident() -> Option<u64> {
+    None
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use super::{cpu_time, peak_resident};
+    use crate::pool;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn busy_threads_use_cpu_time() {
+        let before = cpu_time().unwrap();
+        pool::map(&[0, 1], 2, |_| {
+            let start = Instant::now();
+            while start.elapsed() < Duration::from_millis(20) {
+                std::hint::spin_loop();
+            }
+        });
+        assert!(cpu_time().unwrap() - before >= Duration::from_millis(10));
+    }
+
+    #[test]
+    fn resident_memory_grows_with_the_memory_used() {
+        let bytes = 64 << 20;
+        let touched = vec![1u8; bytes];
+        assert!(peak_resident().unwrap() >= bytes as u64);
+        drop(touched);
+    }
+}
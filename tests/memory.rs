@@ -0,0 +1,34 @@
+//! The counting allocator, in its own test binary: enabling it would count the allocations of
+//! the other tests too.
+
+use aoc_2022_rust::memory::{enable, measure, CountingAllocator, Memory};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn allocations_of_the_thread_are_counted() {
+    enable();
+    let (sum, memory) = measure(|| {
+        let mut numbers: Vec<u64> = Vec::with_capacity(1000);
+        numbers.extend(0..1000);
+        let (_, inner) = measure(|| vec![0u8; 100]);
+        assert_eq!(
+            inner,
+            Some(Memory {
+                allocations: 1,
+                allocated: 100,
+                heap_peak: 100
+            })
+        );
+        // allocated by another thread: not counted here
+        std::thread::spawn(|| vec![0u8; 1_000_000]).join().unwrap();
+        numbers.iter().sum::<u64>()
+    });
+    assert_eq!(sum, 499_500);
+    let memory = memory.unwrap();
+    // spawning the thread allocates a little here too
+    assert!(memory.heap_peak >= 8100 && memory.heap_peak < 1_000_000);
+    assert!(memory.allocations >= 2);
+    assert!(memory.allocated >= 8100 && memory.allocated < 1_000_000);
+}